@@ -7,7 +7,7 @@ use criterion::{
     black_box, criterion_group, criterion_main, AxisScale, BenchmarkId, Criterion,
     PlotConfiguration,
 };
-use ibig::{modular::ModuloRing, ops::DivRem, ubig, UBig};
+use ibig::{modular::ModuloRing, ops::DivRem, rand::RandomBits, ubig, UBig};
 use rand::prelude::*;
 use std::fmt::Write;
 
@@ -15,7 +15,7 @@ fn random_ubig<R>(bits: usize, rng: &mut R) -> UBig
 where
     R: Rng + ?Sized,
 {
-    rng.gen_range(ubig!(1) << (bits - 1)..ubig!(1) << bits)
+    rng.sample(RandomBits::new(bits).exact_top_bit(true))
 }
 
 fn bench_add(criterion: &mut Criterion) {
@@ -70,6 +70,45 @@ fn bench_mul(criterion: &mut Criterion) {
     group.finish();
 }
 
+/// Numbers of a few words.
+fn bench_small(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("small");
+
+    for &bits in &[128, 192, 256] {
+        let a = random_ubig(bits, &mut rng);
+        let b = random_ubig(bits, &mut rng);
+        group.bench_with_input(BenchmarkId::new("clone", bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a).clone())
+        });
+        group.bench_with_input(BenchmarkId::new("add", bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a) + black_box(&b))
+        });
+        group.bench_with_input(BenchmarkId::new("mul", bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a) * black_box(&b))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_mul_unbalanced(criterion: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut group = criterion.benchmark_group("mul_unbalanced");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    for log_bits in 1..=5 {
+        let bits = 10usize.pow(log_bits);
+        let a = random_ubig(3 * bits, &mut rng);
+        let b = random_ubig(bits, &mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |bencher, _| {
+            bencher.iter(|| black_box(&a) * black_box(&b))
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_div(criterion: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(1);
     let mut group = criterion.benchmark_group("div");
@@ -253,6 +292,8 @@ criterion_group!(
     bench_add,
     bench_sub,
     bench_mul,
+    bench_mul_unbalanced,
+    bench_small,
     bench_div,
     bench_gcd,
     bench_to_hex,
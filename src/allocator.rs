@@ -0,0 +1,264 @@
+//! Custom memory allocator.
+//!
+//! By default, the words of numbers and temporary memory used by arithmetic operations come from
+//! the global allocator. [set_allocator] routes them to a different [GlobalAlloc] instead, for
+//! example a memory pool in a `no_std` environment.
+//!
+//! Only the memory owned by [UBig](crate::UBig), [IBig](crate::IBig),
+//! [Scratch](crate::Scratch) and internal temporary buffers is affected. Other allocations, such
+//! as strings and byte vectors returned by conversion functions, still use the global allocator.
+//!
+//! The allocator is chosen once for the whole program, before any number is allocated. Numbers
+//! that fit in a single [Word](crate::Word) don't allocate, but anything larger does, and then
+//! the global allocator stays in use.
+//!
+//! To use a separate memory pool for each request, freed in one shot when the request is done,
+//! set an allocator that dispatches to the pool of the current request. See the second example.
+//!
+//! # Example
+//!
+//! ```
+//! use ibig::{allocator, ubig};
+//! use std::alloc::{GlobalAlloc, Layout, System};
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! struct Counting(AtomicUsize);
+//!
+//! unsafe impl GlobalAlloc for Counting {
+//!     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!         System.alloc(layout)
+//!     }
+//!
+//!     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+//!         System.dealloc(ptr, layout)
+//!     }
+//! }
+//!
+//! static COUNTING: Counting = Counting(AtomicUsize::new(0));
+//!
+//! allocator::set_allocator(&COUNTING).unwrap();
+//! let x = ubig!(1) << 1000;
+//! assert!(COUNTING.0.load(Ordering::Relaxed) > 0);
+//! ```
+//!
+//! # Per-request pools
+//!
+//! Each request gets an arena on its thread. Memory for numbers comes from the arena of the
+//! current request, and the whole arena is freed when the request ends. Numbers created during a
+//! request must be dropped before it ends, on the same thread. Results can be returned in other
+//! forms, such as strings or limbs, which use the global allocator.
+//!
+//! ```
+//! use ibig::{allocator, ubig};
+//! use std::{
+//!     alloc::{GlobalAlloc, Layout, System},
+//!     cell::Cell,
+//!     ptr,
+//! };
+//!
+//! /// Bump allocator over a block of memory.
+//! struct Arena {
+//!     start: *mut u8,
+//!     end: *mut u8,
+//!     next: Cell<*mut u8>,
+//! }
+//!
+//! impl Arena {
+//!     fn layout(size: usize) -> Layout {
+//!         Layout::from_size_align(size, 16).unwrap()
+//!     }
+//!
+//!     fn new(size: usize) -> Arena {
+//!         let start = unsafe { System.alloc(Arena::layout(size)) };
+//!         assert!(!start.is_null());
+//!         Arena { start, end: start.wrapping_add(size), next: Cell::new(start) }
+//!     }
+//!
+//!     fn alloc(&self, layout: Layout) -> Option<*mut u8> {
+//!         let start = (self.next.get() as usize).checked_add(layout.align() - 1)?
+//!             & !(layout.align() - 1);
+//!         let end = start.checked_add(layout.size())?;
+//!         if end > self.end as usize {
+//!             return None;
+//!         }
+//!         self.next.set(end as *mut u8);
+//!         Some(start as *mut u8)
+//!     }
+//!
+//!     fn contains(&self, ptr: *mut u8) -> bool {
+//!         self.start <= ptr && ptr < self.end
+//!     }
+//! }
+//!
+//! impl Drop for Arena {
+//!     fn drop(&mut self) {
+//!         let size = self.end as usize - self.start as usize;
+//!         unsafe { System.dealloc(self.start, Arena::layout(size)) };
+//!     }
+//! }
+//!
+//! thread_local! {
+//!     /// Arena of the request running on this thread, or null.
+//!     static ARENA: Cell<*const Arena> = Cell::new(ptr::null());
+//! }
+//!
+//! fn current_arena() -> Option<&'static Arena> {
+//!     // Safe because the arena is alive while it is current.
+//!     unsafe { ARENA.try_with(Cell::get).unwrap_or(ptr::null()).as_ref() }
+//! }
+//!
+//! /// Allocates from the arena of the current request, or from `System` outside of requests
+//! /// and when the arena is full.
+//! struct PerRequest;
+//!
+//! unsafe impl GlobalAlloc for PerRequest {
+//!     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+//!         match current_arena().and_then(|arena| arena.alloc(layout)) {
+//!             Some(ptr) => ptr,
+//!             None => System.alloc(layout),
+//!         }
+//!     }
+//!
+//!     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+//!         match current_arena() {
+//!             // Freed together with the arena.
+//!             Some(arena) if arena.contains(ptr) => {}
+//!             _ => System.dealloc(ptr, layout),
+//!         }
+//!     }
+//! }
+//!
+//! /// Handle a request with its own arena of `size` bytes.
+//! fn with_arena<T>(size: usize, request: impl FnOnce() -> T) -> T {
+//!     struct Current(Arena);
+//!
+//!     impl Drop for Current {
+//!         fn drop(&mut self) {
+//!             ARENA.with(|arena| arena.set(ptr::null()));
+//!         }
+//!     }
+//!
+//!     let current = Current(Arena::new(size));
+//!     ARENA.with(|arena| arena.set(&current.0));
+//!     request()
+//! }
+//!
+//! static PER_REQUEST: PerRequest = PerRequest;
+//!
+//! allocator::set_allocator(&PER_REQUEST).unwrap();
+//! let s = with_arena(1 << 16, || {
+//!     let x = ubig!(3).pow(1000);
+//!     assert!(current_arena().unwrap().next.get() > current_arena().unwrap().start);
+//!     (&x * &x).to_string()
+//! });
+//! assert_eq!(s, ubig!(3).pow(2000).to_string());
+//! ```
+
+use crate::error::SetAllocatorError;
+use alloc::alloc::{GlobalAlloc, Layout};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// No allocator set, and no memory allocated yet.
+const UNSET: usize = 0;
+/// Memory has been allocated by the global allocator.
+const GLOBAL_IN_USE: usize = 1;
+/// Allocator being set.
+const SETTING: usize = 2;
+/// Allocator set.
+const SET: usize = 3;
+
+static STATE: AtomicUsize = AtomicUsize::new(UNSET);
+static mut ALLOCATOR: Option<&'static dyn GlobalAlloc> = None;
+
+/// Use `allocator` for all memory of numbers.
+///
+/// The allocator can only be set once, before this crate allocates any memory. Returns an error
+/// if an allocator has already been set, or if memory has already been allocated by the global
+/// allocator.
+///
+/// Memory is allocated for any number that doesn't fit in a single [Word](crate::Word), even if
+/// it is dropped right away, for example by [UBig::from_limbs](crate::UBig::from_limbs) with
+/// more than one non-zero limb.
+pub fn set_allocator(allocator: &'static dyn GlobalAlloc) -> Result<(), SetAllocatorError> {
+    match STATE.compare_exchange(UNSET, SETTING, Ordering::Acquire, Ordering::Relaxed) {
+        Ok(_) => {
+            // Safe because only one thread can get here, and ALLOCATOR isn't read until STATE
+            // becomes SET.
+            unsafe { ALLOCATOR = Some(allocator) };
+            STATE.store(SET, Ordering::Release);
+            Ok(())
+        }
+        Err(_) => Err(SetAllocatorError),
+    }
+}
+
+/// The allocator to use for new memory, `None` for the global allocator.
+///
+/// After this returns `None` once, it always does: the custom allocator can no longer be set.
+#[inline]
+fn current() -> Option<&'static dyn GlobalAlloc> {
+    match STATE.load(Ordering::Acquire) {
+        GLOBAL_IN_USE => None,
+        SET => custom(),
+        _ => current_slow(),
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn current_slow() -> Option<&'static dyn GlobalAlloc> {
+    loop {
+        match STATE.compare_exchange(UNSET, GLOBAL_IN_USE, Ordering::Acquire, Ordering::Acquire) {
+            Ok(_) | Err(GLOBAL_IN_USE) => return None,
+            Err(SET) => return custom(),
+            // Another thread is setting the allocator.
+            Err(_) => core::hint::spin_loop(),
+        }
+    }
+}
+
+/// The custom allocator, if one has been set.
+#[inline]
+fn custom() -> Option<&'static dyn GlobalAlloc> {
+    if STATE.load(Ordering::Acquire) == SET {
+        // Safe because ALLOCATOR is never written after STATE becomes SET.
+        unsafe { ALLOCATOR }
+    } else {
+        None
+    }
+}
+
+/// True if memory comes from the global allocator.
+///
+/// Memory from the global allocator may be used for numbers after this returns true.
+#[inline]
+pub(crate) fn is_global() -> bool {
+    current().is_none()
+}
+
+/// Allocate memory. Returns null on failure.
+///
+/// # Safety
+///
+/// `layout` must have non-zero size.
+#[inline]
+pub(crate) unsafe fn alloc(layout: Layout) -> *mut u8 {
+    match current() {
+        Some(allocator) => allocator.alloc(layout),
+        None => alloc::alloc::alloc(layout),
+    }
+}
+
+/// Deallocate memory.
+///
+/// # Safety
+///
+/// `ptr` must have been allocated by [alloc] with the same `layout`.
+#[inline]
+pub(crate) unsafe fn dealloc(ptr: *mut u8, layout: Layout) {
+    match custom() {
+        Some(allocator) => allocator.dealloc(ptr, layout),
+        None => alloc::alloc::dealloc(ptr, layout),
+    }
+}
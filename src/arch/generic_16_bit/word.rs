@@ -1,5 +1,8 @@
 /// Machine word.
-pub(crate) type Word = u16;
+///
+/// [UBig](crate::UBig) stores numbers as sequences of `Word`s, least significant first. The size
+/// of a `Word` depends on the platform.
+pub type Word = u16;
 
 /// Signed machine word.
 pub(crate) type SignedWord = i16;
@@ -1,5 +1,8 @@
 /// Machine word.
-pub(crate) type Word = u32;
+///
+/// [UBig](crate::UBig) stores numbers as sequences of `Word`s, least significant first. The size
+/// of a `Word` depends on the platform.
+pub type Word = u32;
 
 /// Signed machine word.
 pub(crate) type SignedWord = i32;
@@ -1,6 +1,9 @@
 
 /// Machine word.
-pub(crate) type Word = u64;
+///
+/// [UBig](crate::UBig) stores numbers as sequences of `Word`s, least significant first. The size
+/// of a `Word` depends on the platform.
+pub type Word = u64;
 
 /// Signed machine word.
 pub(crate) type SignedWord = i64;
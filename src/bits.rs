@@ -1,7 +1,8 @@
 
-//! Bitwise operators.
+//! Bitwise operators and bit manipulation.
 
 use crate::{
+    add,
     arch::word::Word,
     buffer::Buffer,
     helper_macros,
@@ -9,12 +10,16 @@ use crate::{
     math,
     ops::{AndNot, NextPowerOfTwo, UnsignedAbs},
     primitive::{double_word, PrimitiveSigned, PrimitiveUnsigned, WORD_BITS_USIZE},
+    shift,
     sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
 use core::{
+    iter::FusedIterator,
     mem,
-    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds,
+    },
 };
 
 impl UBig {
@@ -103,6 +108,8 @@ impl UBig {
             Small(word) => {
                 if n < WORD_BITS_USIZE {
                     *self = UBig::from_word(word & !(1 << n))
+                } else {
+                    *self = UBig::from_word(word)
                 }
             }
             Large(buffer) => *self = UBig::without_bit_large(buffer, n),
@@ -208,9 +215,637 @@ impl UBig {
 
         words[..words.len() - 1].iter().all(|x| *x == 0) && words.last().unwrap().is_power_of_two()
     }
+
+    /// Toggle the `n`-th bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(0b101);
+    /// a.toggle_bit(0);
+    /// assert_eq!(a, ubig!(0b100));
+    /// a.toggle_bit(1);
+    /// assert_eq!(a, ubig!(0b110));
+    /// ```
+    #[inline]
+    pub fn toggle_bit(&mut self, n: usize) {
+        if self.bit(n) {
+            self.clear_bit(n);
+        } else {
+            self.set_bit(n);
+        }
+    }
+
+    /// Number of ones in the binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b10110).count_ones(), 3);
+    /// assert_eq!(ubig!(0).count_ones(), 0);
+    /// ```
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.as_words()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Number of zeros in the binary representation, below the top 1 bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b10110).count_zeros(), 2);
+    /// assert_eq!(ubig!(0).count_zeros(), 0);
+    /// ```
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        self.bit_len() - self.count_ones()
+    }
+
+    /// Returns the number of trailing ones in the binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b10111).trailing_ones(), 3);
+    /// assert_eq!(ubig!(0b10110).trailing_ones(), 0);
+    /// assert_eq!(ubig!(0).trailing_ones(), 0);
+    /// ```
+    #[inline]
+    pub fn trailing_ones(&self) -> usize {
+        let words = self.as_words();
+        match words.iter().position(|word| *word != Word::MAX) {
+            Some(idx) => idx * WORD_BITS_USIZE + words[idx].trailing_ones() as usize,
+            None => words.len() * WORD_BITS_USIZE,
+        }
+    }
+
+    /// Iterator over the indices of set bits, in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let a = ubig!(0b10110) | ubig!(1) << 100;
+    /// assert_eq!(a.iter_ones().collect::<Vec<_>>(), [1, 2, 4, 100]);
+    /// ```
+    #[inline]
+    pub fn iter_ones(&self) -> IterOnes<'_> {
+        let words = self.as_words();
+        IterOnes {
+            words,
+            idx: 0,
+            current: words.first().copied().unwrap_or(0),
+        }
+    }
+
+    /// Number of bits that differ between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1011).hamming_distance(&ubig!(0b0110)), 3);
+    /// assert_eq!(ubig!(0).hamming_distance(&(ubig!(1) << 100)), 1);
+    /// ```
+    pub fn hamming_distance(&self, other: &UBig) -> usize {
+        let (a, b) = (self.as_words(), other.as_words());
+        let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+        let common: usize = short
+            .iter()
+            .zip(long)
+            .map(|(x, y)| (x ^ y).count_ones() as usize)
+            .sum();
+        let rest: usize = long[short.len()..]
+            .iter()
+            .map(|x| x.count_ones() as usize)
+            .sum();
+        common + rest
+    }
+
+    /// True if the number of ones in the binary representation is odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1011).parity(), true);
+    /// assert_eq!(ubig!(0b1001).parity(), false);
+    /// assert_eq!(ubig!(0).parity(), false);
+    /// ```
+    #[inline]
+    pub fn parity(&self) -> bool {
+        let folded = self.as_words().iter().fold(0, |acc, word| acc ^ word);
+        folded.count_ones() % 2 == 1
+    }
+
+    /// Index of the first set bit at or after bit `from`.
+    ///
+    /// Returns `None` if there are no set bits at or after `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let a = ubig!(0b100100);
+    /// assert_eq!(a.scan1(0), Some(2));
+    /// assert_eq!(a.scan1(2), Some(2));
+    /// assert_eq!(a.scan1(3), Some(5));
+    /// assert_eq!(a.scan1(6), None);
+    /// ```
+    pub fn scan1(&self, from: usize) -> Option<usize> {
+        let words = self.as_words();
+        let mut idx = from / WORD_BITS_USIZE;
+        if idx >= words.len() {
+            return None;
+        }
+        let mut word = words[idx] & !math::ones_word((from % WORD_BITS_USIZE) as u32);
+        while word == 0 {
+            idx += 1;
+            if idx == words.len() {
+                return None;
+            }
+            word = words[idx];
+        }
+        Some(idx * WORD_BITS_USIZE + word.trailing_zeros() as usize)
+    }
+
+    /// Index of the first clear bit at or after bit `from`.
+    ///
+    /// There are infinitely many leading zeros, so such a bit always exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let a = ubig!(0b110111);
+    /// assert_eq!(a.scan0(0), 3);
+    /// assert_eq!(a.scan0(3), 3);
+    /// assert_eq!(a.scan0(4), 6);
+    /// assert_eq!(a.scan0(100), 100);
+    /// ```
+    pub fn scan0(&self, from: usize) -> usize {
+        let words = self.as_words();
+        let mut idx = from / WORD_BITS_USIZE;
+        if idx >= words.len() {
+            return from;
+        }
+        let mut word = words[idx] | math::ones_word((from % WORD_BITS_USIZE) as u32);
+        while word == Word::MAX {
+            idx += 1;
+            if idx == words.len() {
+                return idx * WORD_BITS_USIZE;
+            }
+            word = words[idx];
+        }
+        idx * WORD_BITS_USIZE + word.trailing_ones() as usize
+    }
+
+    /// Number of leading zeros when the number is represented in `width` bits.
+    ///
+    /// Returns `None` if the number doesn't fit in `width` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b101).leading_zeros_in(8), Some(5));
+    /// assert_eq!(ubig!(0).leading_zeros_in(8), Some(8));
+    /// assert_eq!(ubig!(0x1ff).leading_zeros_in(8), None);
+    /// ```
+    #[inline]
+    pub fn leading_zeros_in(&self, width: usize) -> Option<usize> {
+        width.checked_sub(self.bit_len())
+    }
+
+    /// Bits in a range, shifted down to start at bit 0.
+    ///
+    /// Bits above [bit_len](UBig::bit_len) are zero. `a.bits(start..end)` is equivalent to
+    /// `(a >> start) & ((1 << (end - start)) - 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let a = ubig!(0b1101101);
+    /// assert_eq!(a.bits(2..5), ubig!(0b011));
+    /// assert_eq!(a.bits(3..), ubig!(0b1101));
+    /// assert_eq!(a.bits(..=1), ubig!(0b01));
+    /// assert_eq!(a.bits(100..200), ubig!(0));
+    /// ```
+    pub fn bits<R: RangeBounds<usize>>(&self, range: R) -> UBig {
+        let (start, end) = bit_range(range);
+        let words = self.as_words();
+        let end = end.min(words.len() * WORD_BITS_USIZE);
+        if start >= end {
+            return UBig::from_word(0);
+        }
+        let first = start / WORD_BITS_USIZE;
+        let last = math::ceil_div_usize(end, WORD_BITS_USIZE);
+        let mut buffer = Buffer::allocate(last - first);
+        buffer.extend(&words[first..last]);
+        shift::shr_in_place(&mut buffer, (start % WORD_BITS_USIZE) as u32);
+        let len = end - start;
+        buffer.truncate(math::ceil_div_usize(len, WORD_BITS_USIZE));
+        let top_bits = (len % WORD_BITS_USIZE) as u32;
+        if top_bits != 0 {
+            *buffer.last_mut().unwrap() &= math::ones_word(top_bits);
+        }
+        buffer.into()
+    }
+
+    /// Set all bits in a range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is unbounded above, or if its start is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(0b1000001);
+    /// a.set_bits_range(2..5);
+    /// assert_eq!(a, ubig!(0b1011101));
+    /// ```
+    pub fn set_bits_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, end) = bit_range(range);
+        assert!(end != usize::MAX, "unbounded bit range");
+        if start == end {
+            return;
+        }
+        let len = math::ceil_div_usize(end, WORD_BITS_USIZE);
+        let mut buffer = mem::take(self).into_buffer_with_len(len);
+        set_ones(&mut buffer, start, end);
+        *self = buffer.into();
+    }
+
+    /// Clear all bits in a range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut a = ubig!(0b1111111);
+    /// a.clear_bits_range(2..5);
+    /// assert_eq!(a, ubig!(0b1100011));
+    /// a.clear_bits_range(4..);
+    /// assert_eq!(a, ubig!(0b0011));
+    /// ```
+    pub fn clear_bits_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, end) = bit_range(range);
+        let end = end.min(self.len() * WORD_BITS_USIZE);
+        if start >= end {
+            return;
+        }
+        let mut buffer = mem::take(self).into_buffer_with_len(0);
+        clear_ones(&mut buffer, start, end);
+        *self = buffer.into();
+    }
+
+    /// self + 2^n
+    fn add_pow2(self, n: usize) -> UBig {
+        if let Small(word) = *self.repr() {
+            if n < WORD_BITS_USIZE {
+                let (sum, carry) = word.overflowing_add(1 << n);
+                return UBig::from(double_word(sum, Word::from(carry)));
+            }
+        }
+        let idx = n / WORD_BITS_USIZE;
+        let mut buffer = self.into_buffer_with_len(idx + 1);
+        if add::add_word_in_place(&mut buffer[idx..], 1 << (n % WORD_BITS_USIZE)) {
+            buffer.push_may_reallocate(1);
+        }
+        buffer.into()
+    }
+
+    /// self - 2^n
+    ///
+    /// self must be at least 2^n.
+    fn sub_pow2(self, n: usize) -> UBig {
+        if let Small(word) = *self.repr() {
+            debug_assert!(n < WORD_BITS_USIZE);
+            return UBig::from_word(word - (1 << n));
+        }
+        let idx = n / WORD_BITS_USIZE;
+        let mut buffer = self.into_buffer_with_len(0);
+        let borrow = add::sub_word_in_place(&mut buffer[idx..], 1 << (n % WORD_BITS_USIZE));
+        debug_assert!(!borrow);
+        buffer.into()
+    }
+
+    /// Convert to a [Buffer] with at least `len` words, padded with zeros.
+    fn into_buffer_with_len(self, len: usize) -> Buffer {
+        match self.into_repr() {
+            Small(word) => {
+                let mut buffer = Buffer::allocate(len.max(1));
+                buffer.push(word);
+                buffer.push_zeros(len.saturating_sub(1));
+                buffer
+            }
+            Large(mut buffer) => {
+                if buffer.len() < len {
+                    buffer.ensure_capacity(len);
+                    buffer.push_zeros(len - buffer.len());
+                }
+                buffer
+            }
+        }
+    }
 }
 
+/// Convert a range of bit indices to `(start, end)`, with `usize::MAX` for an unbounded end.
+fn bit_range<R: RangeBounds<usize>>(range: R) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("bit range overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("bit range overflow"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => usize::MAX,
+    };
+    assert!(start <= end, "invalid bit range");
+    (start, end)
+}
+
+/// Set bits in `[start, end)`.
+pub(crate) fn set_ones(words: &mut [Word], start: usize, end: usize) {
+    debug_assert!(start < end && end <= words.len() * WORD_BITS_USIZE);
+    let (start_word, start_bit) = (start / WORD_BITS_USIZE, start % WORD_BITS_USIZE);
+    let (end_word, end_bit) = (end / WORD_BITS_USIZE, end % WORD_BITS_USIZE);
+    if start_word == end_word {
+        words[start_word] |= math::ones_word((end_bit - start_bit) as u32) << start_bit;
+        return;
+    }
+    words[start_word] |= Word::MAX << start_bit;
+    for word in &mut words[start_word + 1..end_word] {
+        *word = Word::MAX;
+    }
+    if end_bit != 0 {
+        words[end_word] |= math::ones_word(end_bit as u32);
+    }
+}
+
+/// Clear bits in `[start, end)`.
+fn clear_ones(words: &mut [Word], start: usize, end: usize) {
+    debug_assert!(start < end && end <= words.len() * WORD_BITS_USIZE);
+    let (start_word, start_bit) = (start / WORD_BITS_USIZE, start % WORD_BITS_USIZE);
+    let (end_word, end_bit) = (end / WORD_BITS_USIZE, end % WORD_BITS_USIZE);
+    if start_word == end_word {
+        words[start_word] &= !(math::ones_word((end_bit - start_bit) as u32) << start_bit);
+        return;
+    }
+    words[start_word] &= math::ones_word(start_bit as u32);
+    for word in &mut words[start_word + 1..end_word] {
+        *word = 0;
+    }
+    if end_bit != 0 {
+        words[end_word] &= !math::ones_word(end_bit as u32);
+    }
+}
+
+/// Iterator over the indices of set bits of a [UBig], in increasing order.
+///
+/// Created by [UBig::iter_ones].
+#[derive(Clone, Debug)]
+pub struct IterOnes<'a> {
+    words: &'a [Word],
+    /// Index of the current word.
+    idx: usize,
+    /// Remaining bits of the current word.
+    current: Word,
+}
+
+impl Iterator for IterOnes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.idx += 1;
+            if self.idx >= self.words.len() {
+                self.idx = self.words.len();
+                return None;
+            }
+            self.current = self.words[self.idx];
+        }
+        let bit = self.current.trailing_zeros();
+        // Clear the lowest set bit.
+        self.current &= self.current - 1;
+        Some(self.idx * WORD_BITS_USIZE + bit as usize)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.current.count_ones() as usize;
+        let rest = self
+            .words
+            .get(self.idx + 1..)
+            .map_or(0, |words| words.len() * WORD_BITS_USIZE);
+        (remaining, Some(remaining + rest))
+    }
+}
+
+impl FusedIterator for IterOnes<'_> {}
+
 impl IBig {
+    /// Returns true if the `n`-th bit of the two's complement binary representation is set.
+    ///
+    /// Negative numbers have infinitely many leading ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(0b10010).bit(1), true);
+    /// assert_eq!(ibig!(0b10010).bit(3), false);
+    /// // -6 = ...11010
+    /// assert_eq!(ibig!(-6).bit(0), false);
+    /// assert_eq!(ibig!(-6).bit(1), true);
+    /// assert_eq!(ibig!(-6).bit(2), false);
+    /// assert_eq!(ibig!(-6).bit(100), true);
+    /// ```
+    #[inline]
+    pub fn bit(&self, n: usize) -> bool {
+        match self.sign() {
+            Positive => self.magnitude().bit(n),
+            Negative => {
+                // -m = !(m - 1) has zeros below the lowest set bit t of m, a one at t,
+                // and the complement of m above t.
+                let magnitude = self.magnitude();
+                let t = magnitude.trailing_zeros().unwrap();
+                n == t || (n > t && !magnitude.bit(n))
+            }
+        }
+    }
+
+    /// Set the `n`-th bit of the two's complement binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// let mut a = ibig!(0b100);
+    /// a.set_bit(0);
+    /// assert_eq!(a, ibig!(0b101));
+    /// let mut a = ibig!(-6);
+    /// a.set_bit(0);
+    /// assert_eq!(a, ibig!(-5));
+    /// a.set_bit(100);
+    /// assert_eq!(a, ibig!(-5));
+    /// ```
+    #[inline]
+    pub fn set_bit(&mut self, n: usize) {
+        match mem::take(self).into_sign_magnitude() {
+            (Positive, mut magnitude) => {
+                magnitude.set_bit(n);
+                *self = IBig::from(magnitude);
+            }
+            (Negative, mut magnitude) => {
+                // See `bit`: only bit t is set at or below the lowest set bit t of m.
+                let t = magnitude.trailing_zeros().unwrap();
+                if n < t {
+                    // -m + 2^n = -(m - 2^n)
+                    magnitude = magnitude.sub_pow2(n);
+                } else if n > t {
+                    magnitude.clear_bit(n);
+                }
+                *self = IBig::from_sign_magnitude(Negative, magnitude);
+            }
+        }
+    }
+
+    /// Clear the `n`-th bit of the two's complement binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// let mut a = ibig!(0b101);
+    /// a.clear_bit(0);
+    /// assert_eq!(a, ibig!(0b100));
+    /// let mut a = ibig!(-5);
+    /// a.clear_bit(0);
+    /// assert_eq!(a, ibig!(-6));
+    /// a.clear_bit(8);
+    /// assert_eq!(a, ibig!(-262));
+    /// ```
+    #[inline]
+    pub fn clear_bit(&mut self, n: usize) {
+        match mem::take(self).into_sign_magnitude() {
+            (Positive, mut magnitude) => {
+                magnitude.clear_bit(n);
+                *self = IBig::from(magnitude);
+            }
+            (Negative, mut magnitude) => {
+                // See `bit`: only bit t is set at or below the lowest set bit t of m.
+                let t = magnitude.trailing_zeros().unwrap();
+                if n == t {
+                    // -m - 2^t = -(m + 2^t)
+                    magnitude = magnitude.add_pow2(n);
+                } else if n > t {
+                    magnitude.set_bit(n);
+                }
+                *self = IBig::from_sign_magnitude(Negative, magnitude);
+            }
+        }
+    }
+
+    /// Toggle the `n`-th bit of the two's complement binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// let mut a = ibig!(-5);
+    /// a.toggle_bit(1);
+    /// assert_eq!(a, ibig!(-7));
+    /// a.toggle_bit(1);
+    /// assert_eq!(a, ibig!(-5));
+    /// ```
+    #[inline]
+    pub fn toggle_bit(&mut self, n: usize) {
+        if self.bit(n) {
+            self.clear_bit(n);
+        } else {
+            self.set_bit(n);
+        }
+    }
+
+    /// Number of ones in the two's complement binary representation.
+    ///
+    /// For negative numbers there are infinitely many ones, and it returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(0b10110).count_ones(), Some(3));
+    /// assert_eq!(ibig!(0).count_ones(), Some(0));
+    /// assert_eq!(ibig!(-1).count_ones(), None);
+    /// ```
+    #[inline]
+    pub fn count_ones(&self) -> Option<usize> {
+        match self.sign() {
+            Positive => Some(self.magnitude().count_ones()),
+            Negative => None,
+        }
+    }
+
+    /// Number of zeros in the two's complement binary representation.
+    ///
+    /// For non-negative numbers there are infinitely many zeros, and it returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// // -6 = ...11010
+    /// assert_eq!(ibig!(-6).count_zeros(), Some(2));
+    /// assert_eq!(ibig!(-1).count_zeros(), Some(0));
+    /// assert_eq!(ibig!(0).count_zeros(), None);
+    /// ```
+    #[inline]
+    pub fn count_zeros(&self) -> Option<usize> {
+        match self.sign() {
+            Positive => None,
+            Negative => Some((self.magnitude() - UBig::from_word(1)).count_ones()),
+        }
+    }
+
+    /// Bit length of the absolute value.
+    ///
+    /// This matches Python's `int.bit_length` and GMP's `mpz_sizeinbase(x, 2)` (except for 0).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(17).bit_len(), 5);
+    /// assert_eq!(ibig!(-17).bit_len(), 5);
+    /// assert_eq!(ibig!(-16).bit_len(), 5);
+    /// assert_eq!(ibig!(0).bit_len(), 0);
+    /// ```
+    #[inline]
+    pub fn bit_len(&self) -> usize {
+        self.magnitude().bit_len()
+    }
+
     /// Returns the number of trailing zeros in the two's complement binary representation.
     ///
     /// In other words, it is the largest `n` such that 2 to the power of `n` divides the number.
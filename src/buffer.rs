@@ -1,11 +1,15 @@
 //! Word buffer.
 
-use crate::{arch::word::Word, ubig::UBig};
+use crate::{allocator, arch::word::Word, memory, ubig::UBig};
 
-use alloc::vec::Vec;
+use alloc::{alloc::Layout, vec::Vec};
 use core::{
-    iter,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    mem::ManuallyDrop,
     ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    slice,
 };
 
 /// Buffer for Words.
@@ -13,9 +17,21 @@ use core::{
 /// UBig operations are usually performed by creating a Buffer with appropriate capacity, filling it
 /// in with Words, and then converting to UBig.
 ///
+/// The memory comes from the [allocator](crate::allocator).
+///
 /// If its capacity is exceeded, the `Buffer` will panic.
-#[derive(Debug, Eq, Hash, PartialEq)]
-pub(crate) struct Buffer(Vec<Word>);
+pub(crate) struct Buffer {
+    /// Start of the allocation, dangling if `capacity == 0`.
+    ptr: NonNull<Word>,
+    /// Number of initialized words.
+    len: usize,
+    /// Number of allocated words.
+    capacity: usize,
+}
+
+// Safe because the memory is owned and not shared.
+unsafe impl Send for Buffer {}
+unsafe impl Sync for Buffer {}
 
 impl Buffer {
     /// Creates a `Buffer` with at least specified capacity.
@@ -25,7 +41,71 @@ impl Buffer {
         if num_words > Buffer::MAX_CAPACITY {
             UBig::panic_number_too_large();
         }
-        Buffer(Vec::with_capacity(Buffer::default_capacity(num_words)))
+        Buffer::with_exact_capacity(Buffer::default_capacity(num_words))
+    }
+
+    /// Creates an empty `Buffer` with exactly `capacity`.
+    fn with_exact_capacity(capacity: usize) -> Buffer {
+        let ptr = if capacity == 0 {
+            NonNull::dangling()
+        } else {
+            // Safe because the size is non-zero.
+            let ptr = unsafe { allocator::alloc(Buffer::layout(capacity)) } as *mut Word;
+            match NonNull::new(ptr) {
+                Some(ptr) => ptr,
+                None => memory::panic_out_of_memory(),
+            }
+        };
+        Buffer {
+            ptr,
+            len: 0,
+            capacity,
+        }
+    }
+
+    /// Memory layout for `capacity` words.
+    ///
+    /// Same as the layout of a `Vec<Word>` with this capacity.
+    #[inline]
+    fn layout(capacity: usize) -> Layout {
+        // capacity <= MAX_CAPACITY, so this doesn't overflow.
+        Layout::array::<Word>(capacity).unwrap()
+    }
+
+    /// Creates a `Buffer` from a `Vec`.
+    ///
+    /// Keeps the allocation unless a custom allocator is used. The capacity may not be compact.
+    pub(crate) fn from_vec(words: Vec<Word>) -> Buffer {
+        if words.len() > Buffer::MAX_CAPACITY {
+            UBig::panic_number_too_large();
+        }
+        if words.capacity() <= Buffer::MAX_CAPACITY && allocator::is_global() {
+            let mut words = ManuallyDrop::new(words);
+            Buffer {
+                // Safe because a Vec pointer is never null.
+                ptr: unsafe { NonNull::new_unchecked(words.as_mut_ptr()) },
+                len: words.len(),
+                capacity: words.capacity(),
+            }
+        } else {
+            let mut buffer = Buffer::with_exact_capacity(words.len());
+            buffer.extend(&words);
+            buffer
+        }
+    }
+
+    /// Convert into a `Vec`.
+    ///
+    /// Keeps the allocation unless a custom allocator is used.
+    pub(crate) fn into_vec(self) -> Vec<Word> {
+        if allocator::is_global() {
+            let buffer = ManuallyDrop::new(self);
+            // Safe because the memory was allocated by the global allocator with the same layout
+            // as a Vec.
+            unsafe { Vec::from_raw_parts(buffer.ptr.as_ptr(), buffer.len, buffer.capacity) }
+        } else {
+            self.to_vec()
+        }
     }
 
     /// Ensure there is enough capacity in the buffer for `num_words`. Will reallocate if there is
@@ -60,7 +140,7 @@ impl Buffer {
     /// Return buffer capacity.
     #[inline]
     pub(crate) fn capacity(&self) -> usize {
-        self.0.capacity()
+        self.capacity
     }
 
     /// Append a Word to the buffer.
@@ -70,8 +150,10 @@ impl Buffer {
     /// Panics if there is not enough capacity.
     #[inline]
     pub(crate) fn push(&mut self, word: Word) {
-        assert!(self.len() < self.capacity());
-        self.0.push(word);
+        assert!(self.len < self.capacity);
+        // Safe because len < capacity.
+        unsafe { ptr::write(self.ptr.as_ptr().add(self.len), word) };
+        self.len += 1;
     }
 
     /// Append a Word and reallocate if necessary.
@@ -87,8 +169,10 @@ impl Buffer {
     ///
     /// Panics if there is not enough capacity.
     pub(crate) fn push_zeros(&mut self, n: usize) {
-        assert!(n <= self.capacity() - self.len());
-        self.0.extend(iter::repeat(0).take(n));
+        assert!(n <= self.capacity - self.len);
+        // Safe because there is enough capacity.
+        unsafe { ptr::write_bytes(self.ptr.as_ptr().add(self.len), 0, n) };
+        self.len += n;
     }
 
     /// Insert `n` zeros in front.
@@ -97,14 +181,26 @@ impl Buffer {
     ///
     /// Panics if there is not enough capacity.
     pub(crate) fn push_zeros_front(&mut self, n: usize) {
-        assert!(n <= self.capacity() - self.len());
-        self.0.splice(..0, iter::repeat(0).take(n));
+        assert!(n <= self.capacity - self.len);
+        // Safe because there is enough capacity.
+        unsafe {
+            let ptr = self.ptr.as_ptr();
+            ptr::copy(ptr, ptr.add(n), self.len);
+            ptr::write_bytes(ptr, 0, n);
+        }
+        self.len += n;
     }
 
     /// Pop the most significant `Word`.
     #[inline]
     pub(crate) fn pop(&mut self) -> Option<Word> {
-        self.0.pop()
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            // Safe because the word at len was initialized.
+            Some(unsafe { ptr::read(self.ptr.as_ptr().add(self.len)) })
+        }
     }
 
     /// Pop leading zero words.
@@ -120,14 +216,17 @@ impl Buffer {
     pub(crate) fn truncate(&mut self, len: usize) {
         assert!(self.len() >= len);
 
-        self.0.truncate(len);
+        self.len = len;
     }
 
     /// Erase first n elements.
     pub(crate) fn erase_front(&mut self, n: usize) {
         assert!(self.len() >= n);
 
-        self.0.drain(..n);
+        let ptr = self.ptr.as_ptr();
+        // Safe because n <= len.
+        unsafe { ptr::copy(ptr.add(n), ptr, self.len - n) };
+        self.len -= n;
     }
 
     /// Clone from `other` and resize if necessary.
@@ -189,8 +288,10 @@ impl Clone for Buffer {
     /// If capacity is exceeded, panic.
     #[inline]
     fn clone_from(&mut self, source: &Buffer) {
-        assert!(self.capacity() >= source.len());
-        self.0.clone_from(&source.0);
+        assert!(self.capacity >= source.len);
+        // Safe because there is enough capacity and the buffers don't overlap.
+        unsafe { ptr::copy_nonoverlapping(source.ptr.as_ptr(), self.ptr.as_ptr(), source.len) };
+        self.len = source.len;
     }
 }
 
@@ -199,14 +300,49 @@ impl Deref for Buffer {
 
     #[inline]
     fn deref(&self) -> &[Word] {
-        &self.0
+        // Safe because the first len words are initialized.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
     }
 }
 
 impl DerefMut for Buffer {
     #[inline]
     fn deref_mut(&mut self) -> &mut [Word] {
-        &mut self.0
+        // Safe because the first len words are initialized.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        if self.capacity != 0 {
+            // Safe because the memory was allocated with the same layout.
+            unsafe {
+                allocator::dealloc(self.ptr.as_ptr() as *mut u8, Buffer::layout(self.capacity))
+            };
+        }
+    }
+}
+
+impl Debug for Buffer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl PartialEq for Buffer {
+    #[inline]
+    fn eq(&self, other: &Buffer) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for Buffer {}
+
+impl Hash for Buffer {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
     }
 }
 
@@ -0,0 +1,194 @@
+//! Factorials, binomial coefficients and related functions.
+
+use crate::{math, sieve, ubig::UBig};
+use alloc::vec::Vec;
+
+/// Up to this n, factorials are computed directly as a product of all numbers.
+const MAX_N_FACTORIAL_DIRECT: usize = 256;
+
+/// If n > this * k, binomial(n, k) is computed as a product of k numbers divided by k!,
+/// rather than from the prime factorization, which requires all primes up to n.
+const BINOMIAL_SPARSE_RATIO: usize = 16;
+
+impl UBig {
+    /// Factorial, `n!`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::factorial(5), ubig!(120));
+    /// assert_eq!(UBig::factorial(0), ubig!(1));
+    /// ```
+    pub fn factorial(n: usize) -> UBig {
+        if n <= MAX_N_FACTORIAL_DIRECT {
+            return product_of_usizes(2..=n);
+        }
+        prime_power_product(&sieve::primes_up_to(n), |p| factorial_exponent(n, p))
+    }
+
+    /// Double factorial, `n!! = n * (n-2) * (n-4) * ...`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::double_factorial(7), ubig!(105));
+    /// assert_eq!(UBig::double_factorial(8), ubig!(384));
+    /// assert_eq!(UBig::double_factorial(0), ubig!(1));
+    /// ```
+    pub fn double_factorial(n: usize) -> UBig {
+        let m = n / 2;
+        if n % 2 == 0 {
+            // (2m)!! = 2^m m!
+            return UBig::factorial(m) << m;
+        }
+        if n <= MAX_N_FACTORIAL_DIRECT {
+            return product_of_usizes((3..=n).step_by(2));
+        }
+        // (2m+1)!! = (2m+1)! / (2^m m!)
+        prime_power_product(&sieve::primes_up_to(n), |p| {
+            if p == 2 {
+                0
+            } else {
+                factorial_exponent(n, p) - factorial_exponent(m, p)
+            }
+        })
+    }
+
+    /// Binomial coefficient, `n choose k`.
+    ///
+    /// Returns 0 if `k > n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::binomial(5, 2), ubig!(10));
+    /// assert_eq!(UBig::binomial(5, 6), ubig!(0));
+    /// ```
+    pub fn binomial(n: usize, k: usize) -> UBig {
+        if k > n {
+            return UBig::from_word(0);
+        }
+        let k = k.min(n - k);
+        if k == 0 {
+            return UBig::from_word(1);
+        }
+        if n / BINOMIAL_SPARSE_RATIO > k {
+            // (n-k+1) * ... * n / k!
+            return product_of_usizes(n - k + 1..=n) / UBig::factorial(k);
+        }
+        prime_power_product(&sieve::primes_up_to(n), |p| {
+            factorial_exponent(n, p) - factorial_exponent(k, p) - factorial_exponent(n - k, p)
+        })
+    }
+
+    /// Multinomial coefficient, `(k_1 + k_2 + ... + k_m)! / (k_1! k_2! ... k_m!)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::multinomial(&[2, 1, 1]), ubig!(12));
+    /// assert_eq!(UBig::multinomial(&[]), ubig!(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum of `ks` overflows `usize`.
+    pub fn multinomial(ks: &[usize]) -> UBig {
+        let mut ks = ks.to_vec();
+        // Largest first, so that the binomials are as cheap as possible.
+        ks.sort_unstable_by(|a, b| b.cmp(a));
+        let mut n: usize = 0;
+        let mut binomials = Vec::with_capacity(ks.len());
+        for k in ks {
+            n = n.checked_add(k).expect("multinomial: sum overflows usize");
+            binomials.push(UBig::binomial(n, k));
+        }
+        binomials.into_iter().product()
+    }
+
+    /// Primorial, the product of all primes `p <= n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::primorial(10), ubig!(210));
+    /// assert_eq!(UBig::primorial(1), ubig!(1));
+    /// ```
+    pub fn primorial(n: usize) -> UBig {
+        product_of_usizes(sieve::primes_up_to(n).into_iter())
+    }
+}
+
+/// The exponent of prime p in n!.
+///
+/// Legendre's formula: floor(n/p) + floor(n/p^2) + ...
+fn factorial_exponent(n: usize, p: usize) -> usize {
+    let mut exponent = 0;
+    let mut m = n / p;
+    while m != 0 {
+        exponent += m;
+        m /= p;
+    }
+    exponent
+}
+
+/// Product of p^exponent(p) over the given primes.
+///
+/// The result is computed as (...((P_k)^2 P_(k-1))^2 ...)^2 P_0 where P_i is the product of the
+/// primes whose exponent has bit i set, so that most of the work is in products of
+/// balanced numbers.
+fn prime_power_product<F>(primes: &[usize], exponent: F) -> UBig
+where
+    F: Fn(usize) -> usize,
+{
+    let mut two_exponent = 0;
+    let mut prime_exponents = Vec::with_capacity(primes.len());
+    for &p in primes {
+        let e = exponent(p);
+        if p == 2 {
+            two_exponent = e;
+        } else if e != 0 {
+            prime_exponents.push((p, e));
+        }
+    }
+    let max_exponent = prime_exponents.iter().map(|&(_, e)| e).max().unwrap_or(0);
+
+    let mut result = UBig::from_word(1);
+    for bit in (0..math::bit_len(max_exponent)).rev() {
+        result = &result * &result;
+        result *= product_of_usizes(
+            prime_exponents
+                .iter()
+                .filter(|&&(_, e)| e >> bit & 1 != 0)
+                .map(|&(p, _)| p),
+        );
+    }
+    result << two_exponent
+}
+
+/// Product of numbers, multiplied in a balanced tree.
+///
+/// Small numbers are first grouped into `usize` products.
+fn product_of_usizes<I>(iter: I) -> UBig
+where
+    I: Iterator<Item = usize>,
+{
+    let mut chunks = Vec::new();
+    let mut chunk: usize = 1;
+    for x in iter {
+        match chunk.checked_mul(x) {
+            Some(product) => chunk = product,
+            None => {
+                chunks.push(UBig::from(chunk));
+                chunk = x;
+            }
+        }
+    }
+    chunks.push(UBig::from(chunk));
+    chunks.into_iter().product()
+}
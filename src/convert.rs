@@ -5,12 +5,13 @@ use crate::{
     buffer::Buffer,
     error::OutOfBoundsError,
     ibig::IBig,
-    primitive::{self, PrimitiveSigned, PrimitiveUnsigned, WORD_BITS, WORD_BYTES},
+    primitive::{self, PrimitiveSigned, PrimitiveUnsigned, WORD_BITS, WORD_BITS_USIZE, WORD_BYTES},
     sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, vec::Vec};
 use core::convert::{TryFrom, TryInto};
+use static_assertions::const_assert;
 
 impl Default for UBig {
     /// Default value: 0.
@@ -153,6 +154,119 @@ impl UBig {
         }
     }
 
+    /// Number of bytes in the minimal representation, as returned by
+    /// [to_le_bytes](UBig::to_le_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0).byte_len(), 0);
+    /// assert_eq!(ubig!(0xff).byte_len(), 1);
+    /// assert_eq!(ubig!(0x100).byte_len(), 2);
+    /// ```
+    #[inline]
+    pub fn byte_len(&self) -> usize {
+        (self.bit_len() + 7) / 8
+    }
+
+    /// Write little-endian bytes to a buffer, padded with zeros to the buffer length.
+    ///
+    /// Returns an error if the number doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::OutOfBoundsError, ubig};
+    /// let mut buffer = [0xff; 4];
+    /// ubig!(0x010203).write_le_bytes_padded(&mut buffer)?;
+    /// assert_eq!(buffer, [3, 2, 1, 0]);
+    /// assert_eq!(
+    ///     ubig!(0x0102030405).write_le_bytes_padded(&mut buffer),
+    ///     Err(OutOfBoundsError)
+    /// );
+    /// # Ok::<(), OutOfBoundsError>(())
+    /// ```
+    pub fn write_le_bytes_padded(&self, buffer: &mut [u8]) -> Result<(), OutOfBoundsError> {
+        let byte_len = self.byte_len();
+        if byte_len > buffer.len() {
+            return Err(OutOfBoundsError);
+        }
+        let (bytes, padding) = buffer.split_at_mut(byte_len);
+        for (chunk, word) in bytes.chunks_mut(WORD_BYTES).zip(self.as_words()) {
+            chunk.copy_from_slice(&word.to_le_bytes()[..chunk.len()]);
+        }
+        for b in padding {
+            *b = 0;
+        }
+        Ok(())
+    }
+
+    /// Write big-endian bytes to a buffer, padded with zeros to the buffer length.
+    ///
+    /// Returns an error if the number doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::OutOfBoundsError, ubig};
+    /// let mut buffer = [0xff; 4];
+    /// ubig!(0x010203).write_be_bytes_padded(&mut buffer)?;
+    /// assert_eq!(buffer, [0, 1, 2, 3]);
+    /// assert_eq!(
+    ///     ubig!(0x0102030405).write_be_bytes_padded(&mut buffer),
+    ///     Err(OutOfBoundsError)
+    /// );
+    /// # Ok::<(), OutOfBoundsError>(())
+    /// ```
+    pub fn write_be_bytes_padded(&self, buffer: &mut [u8]) -> Result<(), OutOfBoundsError> {
+        let byte_len = self.byte_len();
+        if byte_len > buffer.len() {
+            return Err(OutOfBoundsError);
+        }
+        let (padding, bytes) = buffer.split_at_mut(buffer.len() - byte_len);
+        for b in padding {
+            *b = 0;
+        }
+        for (chunk, word) in bytes.rchunks_mut(WORD_BYTES).zip(self.as_words()) {
+            chunk.copy_from_slice(&word.to_be_bytes()[WORD_BYTES - chunk.len()..]);
+        }
+        Ok(())
+    }
+
+    /// Construct from 64-bit words, least significant first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::from_u64_slice(&[3, 1]), (ubig!(1) << 64) + ubig!(3));
+    /// assert_eq!(UBig::from_u64_slice(&[]), ubig!(0));
+    /// ```
+    pub fn from_u64_slice(words: &[u64]) -> UBig {
+        let mut buffer = Buffer::allocate(len_64_to_max_len(words.len()));
+        for &word_64 in words {
+            push_word_64(&mut buffer, word_64);
+        }
+        buffer.into()
+    }
+
+    /// Representation as 64-bit words, least significant first, with no leading zeros.
+    ///
+    /// This is borrowed when the machine word is 64 bits, and converted otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(*((ubig!(1) << 64) + ubig!(3)).as_u64_slice(), [3, 1]);
+    /// assert!(ubig!(0).as_u64_slice().is_empty());
+    /// ```
+    #[inline]
+    pub fn as_u64_slice(&self) -> Cow<'_, [u64]> {
+        Word::words_as_u64(self.as_words())
+    }
+
     /// Convert to f32.
     ///
     /// Round to nearest, breaking ties to even last bit.
@@ -257,6 +371,153 @@ impl UBig {
 }
 
 impl IBig {
+    /// Construct from little-endian two's complement bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// assert_eq!(IBig::from_signed_le_bytes(&[0x80, 0x00]), ibig!(128));
+    /// assert_eq!(IBig::from_signed_le_bytes(&[0x80, 0xff]), ibig!(-128));
+    /// assert_eq!(IBig::from_signed_le_bytes(&[]), ibig!(0));
+    /// ```
+    pub fn from_signed_le_bytes(bytes: &[u8]) -> IBig {
+        match bytes.last() {
+            Some(&last) if last >= 0x80 => {
+                // -x = !x + 1
+                let inverted: Vec<u8> = bytes.iter().map(|b| !b).collect();
+                -IBig::from(UBig::from_le_bytes(&inverted) + UBig::from_word(1))
+            }
+            _ => IBig::from(UBig::from_le_bytes(bytes)),
+        }
+    }
+
+    /// Construct from big-endian two's complement bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// assert_eq!(IBig::from_signed_be_bytes(&[0x00, 0x80]), ibig!(128));
+    /// assert_eq!(IBig::from_signed_be_bytes(&[0xff, 0x80]), ibig!(-128));
+    /// assert_eq!(IBig::from_signed_be_bytes(&[]), ibig!(0));
+    /// ```
+    pub fn from_signed_be_bytes(bytes: &[u8]) -> IBig {
+        match bytes.first() {
+            Some(&first) if first >= 0x80 => {
+                // -x = !x + 1
+                let inverted: Vec<u8> = bytes.iter().map(|b| !b).collect();
+                -IBig::from(UBig::from_be_bytes(&inverted) + UBig::from_word(1))
+            }
+            _ => IBig::from(UBig::from_be_bytes(bytes)),
+        }
+    }
+
+    /// Return little-endian two's complement bytes.
+    ///
+    /// The representation is minimal: the shortest one with the correct sign bit, but at
+    /// least one byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(0).to_signed_le_bytes(), [0]);
+    /// assert_eq!(ibig!(128).to_signed_le_bytes(), [0x80, 0x00]);
+    /// assert_eq!(ibig!(-128).to_signed_le_bytes(), [0x80]);
+    /// assert_eq!(ibig!(-129).to_signed_le_bytes(), [0x7f, 0xff]);
+    /// ```
+    pub fn to_signed_le_bytes(&self) -> Vec<u8> {
+        let mut bytes = match self.sign() {
+            Positive => self.magnitude().to_le_bytes(),
+            // -x = !(x - 1)
+            Negative => {
+                let mut bytes = (self.magnitude() - UBig::from_word(1)).to_le_bytes();
+                for b in bytes.iter_mut() {
+                    *b = !*b;
+                }
+                bytes
+            }
+        };
+        let sign_byte = self.sign_byte();
+        if bytes
+            .last()
+            .map_or(true, |&last| (last ^ sign_byte) >= 0x80)
+        {
+            bytes.push(sign_byte);
+        }
+        bytes
+    }
+
+    /// Return big-endian two's complement bytes.
+    ///
+    /// The representation is minimal: the shortest one with the correct sign bit, but at
+    /// least one byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(0).to_signed_be_bytes(), [0]);
+    /// assert_eq!(ibig!(128).to_signed_be_bytes(), [0x00, 0x80]);
+    /// assert_eq!(ibig!(-128).to_signed_be_bytes(), [0x80]);
+    /// assert_eq!(ibig!(-129).to_signed_be_bytes(), [0xff, 0x7f]);
+    /// ```
+    pub fn to_signed_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.to_signed_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Return little-endian two's complement bytes of a fixed length.
+    ///
+    /// The number is sign-extended to `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::OutOfBoundsError, ibig};
+    /// assert_eq!(ibig!(-2).to_signed_le_bytes_fixed(3), Ok(vec![0xfe, 0xff, 0xff]));
+    /// assert_eq!(ibig!(128).to_signed_le_bytes_fixed(1), Err(OutOfBoundsError));
+    /// ```
+    pub fn to_signed_le_bytes_fixed(&self, len: usize) -> Result<Vec<u8>, OutOfBoundsError> {
+        let mut bytes = self.to_signed_le_bytes();
+        if bytes.len() > len {
+            // Zero is the only number with a minimal representation of 1 byte that fits in 0.
+            if len == 0 && *self.magnitude() == UBig::from_word(0) {
+                return Ok(Vec::new());
+            }
+            return Err(OutOfBoundsError);
+        }
+        bytes.resize(len, self.sign_byte());
+        Ok(bytes)
+    }
+
+    /// Return big-endian two's complement bytes of a fixed length.
+    ///
+    /// The number is sign-extended to `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{error::OutOfBoundsError, ibig};
+    /// assert_eq!(ibig!(-2).to_signed_be_bytes_fixed(3), Ok(vec![0xff, 0xff, 0xfe]));
+    /// assert_eq!(ibig!(128).to_signed_be_bytes_fixed(1), Err(OutOfBoundsError));
+    /// ```
+    pub fn to_signed_be_bytes_fixed(&self, len: usize) -> Result<Vec<u8>, OutOfBoundsError> {
+        let mut bytes = self.to_signed_le_bytes_fixed(len)?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+
+    /// The byte that fills the high bytes of the two's complement representation.
+    fn sign_byte(&self) -> u8 {
+        match self.sign() {
+            Positive => 0,
+            Negative => 0xff,
+        }
+    }
+
     /// Convert to f32.
     ///
     /// Round to nearest, breaking ties to even last bit.
@@ -296,6 +557,61 @@ impl IBig {
     }
 }
 
+const_assert!(64 % WORD_BITS_USIZE == 0);
+const WORDS_PER_U64: usize = 64 / WORD_BITS_USIZE;
+
+/// Conversion of a slice of words to 64-bit words.
+trait WordsAsU64: Sized {
+    fn words_as_u64(words: &[Self]) -> Cow<'_, [u64]>;
+}
+
+impl WordsAsU64 for u64 {
+    #[inline]
+    fn words_as_u64(words: &[u64]) -> Cow<'_, [u64]> {
+        Cow::Borrowed(words)
+    }
+}
+
+macro_rules! impl_words_as_u64_narrow {
+    ($t:ty) => {
+        impl WordsAsU64 for $t {
+            fn words_as_u64(words: &[$t]) -> Cow<'_, [u64]> {
+                let bits = <$t>::BIT_SIZE;
+                let words_64 = words
+                    .chunks(64 / bits as usize)
+                    .map(|chunk| {
+                        chunk.iter().enumerate().fold(0, |acc, (i, &word)| {
+                            acc | u64::from(word) << (i as u32 * bits)
+                        })
+                    })
+                    .collect();
+                Cow::Owned(words_64)
+            }
+        }
+    };
+}
+
+impl_words_as_u64_narrow!(u16);
+impl_words_as_u64_narrow!(u32);
+
+/// Push a 64-bit word to a buffer as Words.
+fn push_word_64(buffer: &mut Buffer, word_64: u64) {
+    for i in 0..WORDS_PER_U64 {
+        buffer.push((word_64 >> (i * WORD_BITS_USIZE)) as Word);
+    }
+}
+
+/// Buffer length needed for `len_64` 64-bit words.
+#[allow(clippy::absurd_extreme_comparisons)]
+fn len_64_to_max_len(len_64: usize) -> usize {
+    // Make sure we always have enough space for leading zero Words.
+    const_assert!(Buffer::MAX_CAPACITY - UBig::MAX_LEN >= WORDS_PER_U64 - 1);
+    #[allow(clippy::redundant_closure)]
+    len_64
+        .checked_mul(WORDS_PER_U64)
+        .unwrap_or_else(|| UBig::panic_number_too_large())
+}
+
 /// Round to even floating point adjustment, based on the bottom
 /// bit of mantissa and additional 2 bits (i.e. 3 bits in units of ULP/4).
 #[inline]
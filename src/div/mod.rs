@@ -11,6 +11,7 @@ use crate::{
 use alloc::alloc::Layout;
 
 mod divide_conquer;
+pub(crate) mod newton;
 mod simple;
 
 /// If divisor or quotient is at most this length, use the simple division algorithm.
@@ -79,8 +80,17 @@ pub(crate) fn rem_by_word(words: &[Word], rhs: Word) -> Word {
         return words[0] & (rhs - 1);
     }
 
+    let fast_div_rhs = FastDivideNormalized::new(rhs << rhs.leading_zeros());
+    fast_rem_by_word(words, rhs, fast_div_rhs)
+}
+
+/// words % rhs
+pub(crate) fn fast_rem_by_word(
+    words: &[Word],
+    rhs: Word,
+    fast_div_rhs: FastDivideNormalized,
+) -> Word {
     let shift = rhs.leading_zeros();
-    let fast_div_rhs = FastDivideNormalized::new(rhs << shift);
     let rem = fast_rem_by_normalized_word(words, fast_div_rhs);
     let a = extend_word(rem) << shift;
     let (_, rem) = fast_div_rhs.div_rem(a);
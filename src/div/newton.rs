@@ -0,0 +1,317 @@
+//! Division using a reciprocal of the divisor computed by Newton iteration.
+
+use crate::{
+    add,
+    arch::word::Word,
+    cmp, div,
+    fast_divide::FastDivideNormalized,
+    memory::{self, Memory, MemoryAllocation},
+    mul,
+    sign::Sign::*,
+};
+use alloc::{alloc::Layout, vec, vec::Vec};
+use core::cmp::Ordering;
+
+/// If the divisor is at most this length, the reciprocal is computed by regular division.
+const MAX_LEN_RECIPROCAL_DIRECT: usize = 64;
+
+/// If the divisor is at least this length, it is worth precomputing its reciprocal when
+/// dividing by it repeatedly.
+pub(crate) const MIN_LEN_REPEATED_DIVISION: usize = 4000;
+
+/// Reciprocal of a normalized divisor.
+///
+/// For a divisor d of length n, the reciprocal is floor((B^(2n) - 1) / d),
+/// where B = 2^WORD_BITS. It has n + 1 words and the top word is 1.
+///
+/// This is the multi-word analogue of [FastDivideNormalized].
+#[derive(Clone)]
+pub(crate) struct Reciprocal {
+    value: Vec<Word>,
+}
+
+impl Reciprocal {
+    /// Compute the reciprocal of a normalized divisor.
+    ///
+    /// The divisor must have at least 2 words and the top bit must be 1.
+    pub(crate) fn new(normalized_divisor: &[Word]) -> Reciprocal {
+        let n = normalized_divisor.len();
+        let mut value = vec![0; n + 1];
+        let mut allocation = MemoryAllocation::new(memory_requirement_reciprocal(n));
+        let mut memory = allocation.memory();
+        reciprocal_in_place(&mut value, normalized_divisor, &mut memory);
+        Reciprocal { value }
+    }
+
+    /// Get the words of the reciprocal.
+    #[inline]
+    pub(crate) fn value(&self) -> &[Word] {
+        &self.value
+    }
+}
+
+/// Memory requirement for computing the reciprocal of a divisor of length n.
+pub(crate) fn memory_requirement_reciprocal(n: usize) -> Layout {
+    // Base case: 2n words + division 2n / n.
+    // Newton step at length n, with h = floor(n/2) + 1:
+    // product: n + h + 1
+    // correction: h + 1 + n + 1
+    // plus a multiplication n * (h + 1),
+    // and then:
+    // product: 2n + 1
+    // plus a multiplication n * (n + 1).
+    //
+    // The recursive call uses less, and it doesn't overlap with the above.
+    memory::add_layout(
+        memory::array_layout::<Word>(3 * n + 5),
+        memory::max_layout(
+            mul::memory_requirement_up_to(2 * n + 1, n),
+            div::memory_requirement_exact(2 * n, n),
+        ),
+    )
+}
+
+/// Memory requirement for division using a reciprocal.
+pub(crate) fn memory_requirement_exact(lhs_len: usize, rhs_len: usize) -> Layout {
+    assert!(lhs_len >= rhs_len);
+    // Quotient chunks are at most rhs_len long.
+    let k = (lhs_len - rhs_len).min(rhs_len);
+    // product: k + rhs_len + 2
+    // plus a multiplication (k + 1) * (rhs_len + 1),
+    // or a multiplication k * rhs_len.
+    memory::add_layout(
+        memory::array_layout::<Word>(k + rhs_len + 2),
+        mul::memory_requirement_up_to(k + rhs_len + 2, k + 1),
+    )
+}
+
+/// value = floor((B^(2n) - 1) / d)
+///
+/// d must be normalized, value must have length n + 1.
+fn reciprocal_in_place(value: &mut [Word], d: &[Word], memory: &mut Memory) {
+    let n = d.len();
+    debug_assert!(n >= 2 && value.len() == n + 1 && d[n - 1].leading_zeros() == 0);
+
+    if n <= MAX_LEN_RECIPROCAL_DIRECT {
+        // Divide B^(2n) - 1 directly. The top n words are B^n - 1 >= d, so the quotient
+        // overflows by exactly 1 into the top word.
+        let (x, mut memory) = memory.allocate_slice_fill::<Word>(2 * n, Word::MAX);
+        let fast_div_top = FastDivideNormalized::new(d[n - 1]);
+        let overflow = div::div_rem_in_place(x, d, fast_div_top, &mut memory);
+        assert!(overflow);
+        value[..n].copy_from_slice(&x[n..]);
+        value[n] = 1;
+        return;
+    }
+
+    // Compute the reciprocal v_h of the top h words of d, then take one Newton step:
+    // v ~= v_h * B^(n-h) + v_h * e / B^(2h)
+    // where e = B^(n+h) - d * v_h, |e| < 3 B^n.
+    //
+    // Then correct the result to be exact, which takes a few steps at most because
+    // the Newton step doubles the precision.
+    let h = n / 2 + 1;
+    for word in value.iter_mut() {
+        *word = 0;
+    }
+    reciprocal_in_place(&mut value[n - h..], &d[n - h..], memory);
+
+    {
+        // product = d * v_h
+        let (product, mut memory) = memory.allocate_slice_fill::<Word>(n + h + 1, 0);
+        let overflow = mul::add_signed_mul(product, Positive, d, &value[n - h..], &mut memory);
+        assert!(overflow == 0);
+
+        // e = B^(n+h) - product
+        // |e| < 3 B^n, so e fits in n + 1 words.
+        let e_sign = if product[n + h] != 0 {
+            assert!(product[n + h] == 1);
+            product[n + h] = 0;
+            Negative
+        } else {
+            for word in product[..n + h].iter_mut() {
+                *word = !*word;
+            }
+            let overflow = add::add_one_in_place(&mut product[..n + h]);
+            assert!(!overflow);
+            Positive
+        };
+        assert!(product[n + 1..].iter().all(|word| *word == 0));
+        let e = &product[..n + 1];
+
+        // correction = v_h * e
+        let (correction, mut memory) = memory.allocate_slice_fill::<Word>(n + h + 2, 0);
+        let overflow = mul::add_signed_mul(correction, Positive, e, &value[n - h..], &mut memory);
+        assert!(overflow == 0);
+
+        // value += e_sign * correction / B^(2h)
+        let overflow = add::add_signed_in_place(value, e_sign, &correction[2 * h..]);
+        assert!(overflow == 0);
+    }
+
+    // rem = B^(2n) - 1 - d * value
+    // Adjust value until 0 <= rem < d.
+    let (rem, mut memory) = memory.allocate_slice_fill::<Word>(2 * n + 1, 0);
+    let overflow = mul::add_signed_mul(rem, Negative, d, value, &mut memory);
+    let mut rem_negative = match overflow {
+        0 => false,
+        -1 => true,
+        _ => unreachable!(),
+    };
+    // rem -= 1
+    let overflow = add::sub_one_in_place(rem);
+    rem_negative ^= overflow;
+    // rem += B^(2n)
+    let overflow = add::add_one_in_place(&mut rem[2 * n..]);
+    rem_negative ^= overflow;
+
+    while rem_negative {
+        let overflow = add::sub_one_in_place(value);
+        assert!(!overflow);
+        let overflow = add::add_in_place(rem, d);
+        rem_negative ^= overflow;
+    }
+    while rem[n..].iter().any(|word| *word != 0)
+        || cmp::cmp_same_len(&rem[..n], d) != Ordering::Less
+    {
+        let overflow = add::add_one_in_place(value);
+        assert!(!overflow);
+        let overflow = add::sub_in_place(rem, d);
+        assert!(!overflow);
+    }
+    debug_assert!(value[n] == 1);
+}
+
+/// Divide lhs by rhs using the reciprocal of rhs, replacing the top words of lhs by the quotient
+/// and the bottom words of lhs by the remainder.
+///
+/// rhs must have at least 2 words and the top bit must be 1.
+///
+/// lhs = [lhs / rhs, lhs % rhs]
+///
+/// Returns carry in the quotient. It is at most 1 because rhs is normalized.
+#[must_use]
+pub(crate) fn div_rem_in_place(
+    lhs: &mut [Word],
+    rhs: &[Word],
+    reciprocal: &Reciprocal,
+    memory: &mut Memory,
+) -> bool {
+    let n = rhs.len();
+    assert!(n >= 2 && lhs.len() >= n && reciprocal.value().len() == n + 1);
+
+    let mut m = lhs.len();
+    let overflow = cmp::cmp_same_len(&lhs[m - n..], rhs) != Ordering::Less;
+    if overflow {
+        let overflow = add::sub_same_len_in_place(&mut lhs[m - n..], rhs);
+        assert!(!overflow);
+    }
+    while m > n {
+        let k = (m - n).min(n);
+        div_rem_in_place_small_quotient(&mut lhs[m - n - k..m], rhs, reciprocal.value(), memory);
+        m -= k;
+    }
+    overflow
+}
+
+/// Divide lhs by rhs using the reciprocal of rhs.
+/// Quotient length <= divisor length, and there is no quotient carry.
+///
+/// lhs = [lhs / rhs, lhs % rhs]
+fn div_rem_in_place_small_quotient(
+    lhs: &mut [Word],
+    rhs: &[Word],
+    reciprocal: &[Word],
+    memory: &mut Memory,
+) {
+    // Barrett reduction.
+    // Menezes, van Oorschot, Vanstone, Handbook of Applied Cryptography, Algorithm 14.42.
+    let n = rhs.len();
+    let k = lhs.len() - n;
+    debug_assert!(k >= 1 && k <= n);
+
+    // Approximate quotient:
+    // q = floor(floor(lhs / B^(n-1)) * reciprocal / B^(n+1))
+    // It is never too large, and too small by at most 2.
+    let (product, mut memory) = memory.allocate_slice_fill::<Word>(k + n + 2, 0);
+    let overflow = mul::add_signed_mul(product, Positive, reciprocal, &lhs[n - 1..], &mut memory);
+    assert!(overflow == 0);
+    let q = &mut product[n + 1..];
+    assert!(q[k] == 0);
+    let q = &mut q[..k];
+
+    // lhs -= q * rhs
+    let overflow = mul::add_signed_mul(lhs, Negative, rhs, q, &mut memory);
+    assert!(overflow == 0);
+
+    // Now the remainder is in lhs[..n+1] and may be too large.
+    while lhs[n] != 0 || cmp::cmp_same_len(&lhs[..n], rhs) != Ordering::Less {
+        let overflow = add::sub_in_place(&mut lhs[..n + 1], rhs);
+        assert!(!overflow);
+        let overflow = add::add_one_in_place(q);
+        assert!(!overflow);
+    }
+    lhs[n..].copy_from_slice(q);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{buffer::Buffer, ops::DivRem, primitive::WORD_BITS_USIZE, ubig::UBig};
+    use rand::prelude::*;
+
+    fn ubig_from_words(words: &[Word]) -> UBig {
+        let mut buffer = Buffer::allocate(words.len());
+        buffer.extend(words);
+        buffer.into()
+    }
+
+    #[test]
+    fn test_reciprocal() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for &n in &[2, 3, 64, 65, 100, 129, 300, 1000] {
+            let mut random: Vec<Word> = (0..n).map(|_| rng.gen()).collect();
+            random[n - 1] |= Word::MAX ^ (Word::MAX >> 1);
+            let mut power_of_two = vec![0; n];
+            power_of_two[n - 1] = Word::MAX ^ (Word::MAX >> 1);
+            let all_ones = vec![Word::MAX; n];
+
+            for d in &[random, power_of_two, all_ones] {
+                let reciprocal = Reciprocal::new(d);
+                let expected = ((UBig::from_word(1) << (2 * n * WORD_BITS_USIZE))
+                    - UBig::from_word(1))
+                    / ubig_from_words(d);
+                assert_eq!(ubig_from_words(reciprocal.value()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for &(m, n) in &[
+            (2, 2),
+            (5, 2),
+            (200, 100),
+            (201, 100),
+            (1000, 100),
+            (1000, 300),
+        ] {
+            let mut d: Vec<Word> = (0..n).map(|_| rng.gen()).collect();
+            d[n - 1] |= Word::MAX ^ (Word::MAX >> 1);
+            let lhs: Vec<Word> = (0..m).map(|_| rng.gen()).collect();
+            let reciprocal = Reciprocal::new(&d);
+
+            let mut allocation = MemoryAllocation::new(memory_requirement_exact(m, n));
+            let mut memory = allocation.memory();
+            let mut result = lhs.clone();
+            let overflow = div_rem_in_place(&mut result, &d, &reciprocal, &mut memory);
+
+            let (q, r) = ubig_from_words(&lhs).div_rem(ubig_from_words(&d));
+            let q_result = ubig_from_words(&result[n..])
+                + (UBig::from_word(overflow as Word) << ((m - n) * WORD_BITS_USIZE));
+            assert_eq!(q_result, q);
+            assert_eq!(ubig_from_words(&result[..n]), r);
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! Precomputed divisors for repeated division.
+
+use crate::{
+    arch::word::Word,
+    buffer::Buffer,
+    div::{self, newton::Reciprocal},
+    fast_divide::FastDivideNormalized,
+    memory::{Memory, MemoryAllocation},
+    shift,
+    ubig::{Repr, UBig},
+};
+use alloc::{alloc::Layout, vec::Vec};
+use core::fmt::{self, Debug, Formatter};
+
+/// A divisor prepared for repeated division.
+///
+/// Dividing many numbers by the same [Divisor] is faster than using the `/` and `%` operators,
+/// because the work that only depends on the divisor is done once, in [Divisor::new].
+///
+/// # Examples
+///
+/// ```
+/// # use ibig::{ubig, Divisor};
+/// let divisor = Divisor::new(&ubig!(1000));
+/// assert_eq!(divisor.div_rem(&ubig!(123456)), (ubig!(123), ubig!(456)));
+/// assert_eq!(divisor.div(&ubig!(123456)), ubig!(123));
+/// assert_eq!(divisor.rem(&ubig!(123456)), ubig!(456));
+/// ```
+#[derive(Clone)]
+pub struct Divisor(DivisorRepr);
+
+#[derive(Clone)]
+enum DivisorRepr {
+    Small(DivisorSmall),
+    Large(DivisorLarge),
+}
+
+/// A divisor that fits in a single word.
+#[derive(Clone)]
+struct DivisorSmall {
+    divisor: Word,
+    fast_div: FastDivideNormalized,
+}
+
+/// A divisor of at least 2 words.
+#[derive(Clone)]
+pub(crate) struct DivisorLarge {
+    normalized_divisor: Vec<Word>,
+    shift: u32,
+    fast_div_top: FastDivideNormalized,
+    /// Reciprocal of the normalized divisor, if it is large enough to be worth it.
+    reciprocal: Option<Reciprocal>,
+}
+
+impl Divisor {
+    /// Prepare a divisor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, Divisor};
+    /// let divisor = Divisor::new(&ubig!(7));
+    /// assert_eq!(divisor.divisor(), ubig!(7));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    #[inline]
+    pub fn new(divisor: &UBig) -> Divisor {
+        match divisor.repr() {
+            Repr::Small(0) => panic!("divide by 0"),
+            Repr::Small(word) => Divisor(DivisorRepr::Small(DivisorSmall::new(*word))),
+            Repr::Large(words) => Divisor(DivisorRepr::Large(DivisorLarge::new(words))),
+        }
+    }
+
+    /// Prepare a divisor that fits in a single [Word].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, Divisor};
+    /// let divisor = Divisor::from_word(7);
+    /// assert_eq!(divisor.divisor(), ubig!(7));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    #[inline]
+    pub fn from_word(divisor: Word) -> Divisor {
+        if divisor == 0 {
+            panic!("divide by 0");
+        }
+        Divisor(DivisorRepr::Small(DivisorSmall::new(divisor)))
+    }
+
+    /// The divisor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, Divisor};
+    /// let divisor = Divisor::new(&ubig!(7));
+    /// assert_eq!(divisor.divisor(), ubig!(7));
+    /// ```
+    #[inline]
+    pub fn divisor(&self) -> UBig {
+        match &self.0 {
+            DivisorRepr::Small(divisor) => UBig::from_word(divisor.divisor),
+            DivisorRepr::Large(divisor) => divisor.divisor(),
+        }
+    }
+
+    /// Returns (`lhs / self`, `lhs % self`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, Divisor};
+    /// let divisor = Divisor::new(&ubig!(7));
+    /// assert_eq!(divisor.div_rem(&ubig!(23)), (ubig!(3), ubig!(2)));
+    /// ```
+    #[inline]
+    pub fn div_rem(&self, lhs: &UBig) -> (UBig, UBig) {
+        match &self.0 {
+            DivisorRepr::Small(divisor) => divisor.div_rem(lhs),
+            DivisorRepr::Large(divisor) => divisor.div_rem(lhs),
+        }
+    }
+
+    /// Returns `lhs / self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, Divisor};
+    /// let divisor = Divisor::new(&ubig!(7));
+    /// assert_eq!(divisor.div(&ubig!(23)), ubig!(3));
+    /// ```
+    #[inline]
+    pub fn div(&self, lhs: &UBig) -> UBig {
+        match &self.0 {
+            DivisorRepr::Small(divisor) => divisor.div(lhs),
+            DivisorRepr::Large(divisor) => divisor.div_rem(lhs).0,
+        }
+    }
+
+    /// Returns `lhs % self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, Divisor};
+    /// let divisor = Divisor::new(&ubig!(7));
+    /// assert_eq!(divisor.rem(&ubig!(23)), ubig!(2));
+    /// ```
+    #[inline]
+    pub fn rem(&self, lhs: &UBig) -> UBig {
+        match &self.0 {
+            DivisorRepr::Small(divisor) => divisor.rem(lhs),
+            DivisorRepr::Large(divisor) => divisor.div_rem(lhs).1,
+        }
+    }
+
+    /// Returns `lhs % self` for a divisor that fits in a single [Word].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, Divisor};
+    /// let divisor = Divisor::from_word(7);
+    /// assert_eq!(divisor.rem_word(&ubig!(23)), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor doesn't fit in a [Word].
+    #[inline]
+    pub fn rem_word(&self, lhs: &UBig) -> Word {
+        match &self.0 {
+            DivisorRepr::Small(divisor) => divisor.rem_word(lhs),
+            DivisorRepr::Large(_) => panic!("divisor doesn't fit in a Word"),
+        }
+    }
+}
+
+impl Debug for Divisor {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Divisor").field(&self.divisor()).finish()
+    }
+}
+
+impl DivisorSmall {
+    /// divisor must be non-zero.
+    #[inline]
+    fn new(divisor: Word) -> DivisorSmall {
+        debug_assert!(divisor != 0);
+        DivisorSmall {
+            divisor,
+            fast_div: FastDivideNormalized::new(divisor << divisor.leading_zeros()),
+        }
+    }
+
+    fn div_rem(&self, lhs: &UBig) -> (UBig, UBig) {
+        match lhs.repr() {
+            Repr::Small(word) => (
+                UBig::from_word(*word / self.divisor),
+                UBig::from_word(*word % self.divisor),
+            ),
+            Repr::Large(words) => {
+                let mut buffer = Buffer::allocate(words.len());
+                buffer.extend(&words[..]);
+                let rem = div::fast_div_by_word_in_place(&mut buffer, self.divisor, self.fast_div);
+                (buffer.into(), UBig::from_word(rem))
+            }
+        }
+    }
+
+    fn div(&self, lhs: &UBig) -> UBig {
+        match lhs.repr() {
+            Repr::Small(word) => UBig::from_word(*word / self.divisor),
+            Repr::Large(_) => self.div_rem(lhs).0,
+        }
+    }
+
+    #[inline]
+    fn rem(&self, lhs: &UBig) -> UBig {
+        UBig::from_word(self.rem_word(lhs))
+    }
+
+    fn rem_word(&self, lhs: &UBig) -> Word {
+        match lhs.repr() {
+            Repr::Small(word) => *word % self.divisor,
+            Repr::Large(words) => div::fast_rem_by_word(words, self.divisor, self.fast_div),
+        }
+    }
+}
+
+impl DivisorLarge {
+    /// Prepare a divisor of at least 2 words.
+    pub(crate) fn new(divisor: &[Word]) -> DivisorLarge {
+        let mut normalized_divisor = divisor.to_vec();
+        let (shift, fast_div_top) = div::normalize_large(&mut normalized_divisor);
+        let reciprocal = if normalized_divisor.len() >= div::newton::MIN_LEN_REPEATED_DIVISION {
+            Some(Reciprocal::new(&normalized_divisor))
+        } else {
+            None
+        };
+        DivisorLarge {
+            normalized_divisor,
+            shift,
+            fast_div_top,
+            reciprocal,
+        }
+    }
+
+    /// The divisor shifted left so that the top bit is 1.
+    #[inline]
+    pub(crate) fn normalized_divisor(&self) -> &[Word] {
+        &self.normalized_divisor
+    }
+
+    #[inline]
+    pub(crate) fn shift(&self) -> u32 {
+        self.shift
+    }
+
+    pub(crate) fn divisor(&self) -> UBig {
+        let mut buffer = Buffer::allocate(self.normalized_divisor.len());
+        buffer.extend(&self.normalized_divisor);
+        let low_bits = shift::shr_in_place(&mut buffer, self.shift);
+        assert!(low_bits == 0);
+        buffer.into()
+    }
+
+    /// Memory requirement for `div_rem_in_place`.
+    pub(crate) fn memory_requirement(&self, lhs_len: usize) -> Layout {
+        let n = self.normalized_divisor.len();
+        match self.reciprocal {
+            Some(_) => div::newton::memory_requirement_exact(lhs_len, n),
+            None => div::memory_requirement_exact(lhs_len, n),
+        }
+    }
+
+    /// Divide lhs by the normalized divisor.
+    ///
+    /// lhs = [lhs / normalized_divisor, lhs % normalized_divisor]
+    ///
+    /// Returns carry in the quotient.
+    #[must_use]
+    pub(crate) fn div_rem_in_place(&self, lhs: &mut [Word], memory: &mut Memory) -> bool {
+        match &self.reciprocal {
+            Some(reciprocal) => {
+                div::newton::div_rem_in_place(lhs, &self.normalized_divisor, reciprocal, memory)
+            }
+            None => div::div_rem_in_place(lhs, &self.normalized_divisor, self.fast_div_top, memory),
+        }
+    }
+
+    fn div_rem(&self, lhs: &UBig) -> (UBig, UBig) {
+        let n = self.normalized_divisor.len();
+        let words = match lhs.repr() {
+            Repr::Large(words) if words.len() >= n => words,
+            _ => return (UBig::from_word(0), lhs.clone()),
+        };
+
+        // The extra top word is less than 2^shift, so the quotient doesn't overflow.
+        let mut buffer = Buffer::allocate(words.len() + 1);
+        buffer.extend(&words[..]);
+        let carry = shift::shl_in_place(&mut buffer, self.shift);
+        buffer.push(carry);
+
+        let mut allocation = MemoryAllocation::new(self.memory_requirement(buffer.len()));
+        let mut memory = allocation.memory();
+        let overflow = self.div_rem_in_place(&mut buffer, &mut memory);
+        assert!(!overflow);
+
+        let mut rem = Buffer::allocate(n);
+        rem.extend(&buffer[..n]);
+        let low_bits = shift::shr_in_place(&mut rem, self.shift);
+        debug_assert!(low_bits == 0);
+        buffer.erase_front(n);
+        (buffer.into(), rem.into())
+    }
+}
@@ -35,3 +35,17 @@ impl Display for ParseError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
+
+/// Error setting a custom allocator, because one has already been set or memory has already been
+/// allocated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SetAllocatorError;
+
+impl Display for SetAllocatorError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("allocator already set or in use")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SetAllocatorError {}
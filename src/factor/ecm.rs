@@ -0,0 +1,200 @@
+//! Lenstra's elliptic curve method.
+//!
+//! Uses Montgomery curves `B y^2 = x^3 + A x^2 + x` in projective `(X : Z)` coordinates, with
+//! Suyama's parametrization, which guarantees a group order divisible by 12.
+
+use crate::{
+    factor::{self, p_minus_1::STAGE_2_RATIO},
+    modular::{Modulo, ModuloRing},
+    sieve,
+    ubig::UBig,
+};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Giant step size in stage 2. Baby steps are the odd numbers below `GIANT_STEP / 2`.
+const GIANT_STEP: usize = 210;
+
+/// Lenstra's elliptic curve method.
+///
+/// Tries `curves` curves with stage 1 bound `bound` and stage 2 bound `50 * bound`. A curve
+/// finds a prime factor `p` if its group order modulo `p` is `bound`-smooth, except for
+/// possibly one prime factor up to `50 * bound`.
+///
+/// Returns a proper divisor `1 < d < n` of `n`, or `None` if none was found.
+///
+/// # Example
+///
+/// ```
+/// # use ibig::{factor, ubig};
+/// let n = ubig!(1000000000000000003) * ubig!(1000000000000000009);
+/// let d = factor::ecm(&n, 20000, 50).unwrap();
+/// assert!(d == ubig!(1000000000000000003) || d == ubig!(1000000000000000009));
+/// ```
+pub fn ecm(n: &UBig, bound: usize, curves: usize) -> Option<UBig> {
+    if *n <= UBig::from_word(3) {
+        return None;
+    }
+    if n % 2u8 == 0 {
+        return Some(UBig::from_word(2));
+    }
+    ecm_curves(n, bound, 0..curves)
+}
+
+/// Try the curves with the given indices, for odd n > 3.
+pub(crate) fn ecm_curves(n: &UBig, bound: usize, curves: Range<usize>) -> Option<UBig> {
+    let ring = ModuloRing::new(n);
+    let exponents = factor::stage_1_exponents(bound);
+    let stage_2_primes = sieve::primes_up_to(bound.saturating_mul(STAGE_2_RATIO));
+    // Stage 2 requires p >= GIANT_STEP / 2.
+    let first = stage_2_primes
+        .iter()
+        .position(|&p| p > bound.max(GIANT_STEP / 2))
+        .unwrap_or(stage_2_primes.len());
+    let stage_2_primes = &stage_2_primes[first..];
+    for curve in curves {
+        // Suyama's parametrization requires sigma > 5.
+        if let Some(d) = ecm_curve(n, &ring, curve + 6, &exponents, stage_2_primes) {
+            return Some(d);
+        }
+    }
+    None
+}
+
+/// A point in (X : Z) coordinates. The y coordinate is not needed.
+#[derive(Clone)]
+struct Point<'a> {
+    x: Modulo<'a>,
+    z: Modulo<'a>,
+}
+
+/// A Montgomery curve, represented by (A + 2) / 4.
+struct Curve<'a> {
+    a24: Modulo<'a>,
+}
+
+impl<'a> Curve<'a> {
+    /// 2P
+    fn double(&self, p: &Point<'a>) -> Point<'a> {
+        let sum = &p.x + &p.z;
+        let sum2 = &sum * &sum;
+        let diff = &p.x - &p.z;
+        let diff2 = &diff * &diff;
+        // 4 X Z
+        let xz4 = &sum2 - &diff2;
+        let z = &xz4 * (&diff2 + &self.a24 * &xz4);
+        Point { x: sum2 * diff2, z }
+    }
+
+    /// P + Q, given P - Q.
+    fn add(p: &Point<'a>, q: &Point<'a>, diff: &Point<'a>) -> Point<'a> {
+        let u = (&p.x - &p.z) * (&q.x + &q.z);
+        let v = (&p.x + &p.z) * (&q.x - &q.z);
+        let sum = &u + &v;
+        let difference = u - v;
+        Point {
+            x: &diff.z * &sum * &sum,
+            z: &diff.x * &difference * &difference,
+        }
+    }
+
+    /// (kP, (k+1)P) for k > 0, by the Montgomery ladder.
+    fn mul(&self, p: &Point<'a>, k: &UBig) -> (Point<'a>, Point<'a>) {
+        let mut r0 = p.clone();
+        let mut r1 = self.double(p);
+        for i in (0..k.bit_len() - 1).rev() {
+            // r1 - r0 = p
+            if k.bit(i) {
+                r0 = Curve::add(&r1, &r0, p);
+                r1 = self.double(&r1);
+            } else {
+                r1 = Curve::add(&r1, &r0, p);
+                r0 = self.double(&r0);
+            }
+        }
+        (r0, r1)
+    }
+}
+
+/// Run one curve with Suyama's parameter sigma.
+fn ecm_curve(
+    n: &UBig,
+    ring: &ModuloRing,
+    sigma: usize,
+    exponents: &[UBig],
+    stage_2_primes: &[usize],
+) -> Option<UBig> {
+    let one = UBig::from_word(1);
+    let proper_divisor = |x: &Modulo| {
+        let g = x.residue().gcd(n);
+        if g == one || g == *n {
+            None
+        } else {
+            Some(g)
+        }
+    };
+
+    // u = sigma^2 - 5, v = 4 sigma
+    // Starting point: (u^3 : v^3)
+    // (A + 2) / 4 = (v - u)^3 (3u + v) / (16 u^3 v)
+    let sigma = ring.from(sigma);
+    let u = &sigma * &sigma - ring.from(5u8);
+    let v = ring.from(4u8) * &sigma;
+    let u3 = &u * &u * &u;
+    let v3 = &v * &v * &v;
+    let v_minus_u = &v - &u;
+    let denominator = ring.from(16u8) * &u3 * &v;
+    let a24 = match denominator.inverse() {
+        Some(inverse) => {
+            &v_minus_u * &v_minus_u * &v_minus_u * (ring.from(3u8) * &u + &v) * inverse
+        }
+        None => return proper_divisor(&denominator),
+    };
+    let curve = Curve { a24 };
+
+    // Stage 1: Q = E P where E is the product of the exponents.
+    let mut q = Point { x: u3, z: v3 };
+    for exponent in exponents {
+        q = curve.mul(&q, exponent).0;
+    }
+    let g = q.z.residue().gcd(n);
+    if g != one {
+        return if g == *n { None } else { Some(g) };
+    }
+    if stage_2_primes.is_empty() {
+        return None;
+    }
+
+    // Stage 2: for each prime p = m GIANT_STEP +- j, the x coordinates of (m GIANT_STEP) Q and
+    // j Q are equal modulo a prime factor if p Q is the identity modulo that prime.
+    // Baby steps: j Q for odd j < GIANT_STEP / 2, indexed by j / 2.
+    let mut baby = Vec::with_capacity(GIANT_STEP / 4);
+    let q2 = curve.double(&q);
+    baby.push(q.clone());
+    baby.push(Curve::add(&q2, &q, &q));
+    while baby.len() < GIANT_STEP / 4 {
+        let next = Curve::add(&baby[baby.len() - 1], &q2, &baby[baby.len() - 2]);
+        baby.push(next);
+    }
+    // Giant steps: current = m R, next = (m + 1) R where R = GIANT_STEP Q.
+    let r = curve.mul(&q, &UBig::from(GIANT_STEP)).0;
+    let mut m = (stage_2_primes[0] + GIANT_STEP / 2) / GIANT_STEP;
+    let (mut current, mut next) = curve.mul(&r, &UBig::from(m));
+    let mut product = ring.from(1u8);
+    for &p in stage_2_primes {
+        while p > m * GIANT_STEP + GIANT_STEP / 2 {
+            let after_next = Curve::add(&next, &r, &current);
+            current = next;
+            next = after_next;
+            m += 1;
+        }
+        let j = if p > m * GIANT_STEP {
+            p - m * GIANT_STEP
+        } else {
+            m * GIANT_STEP - p
+        };
+        let b = &baby[j / 2];
+        product *= &current.x * &b.z - &b.x * &current.z;
+    }
+    proper_divisor(&product)
+}
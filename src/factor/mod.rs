@@ -0,0 +1,178 @@
+//! Integer factorization.
+//!
+//! [factorize] combines the individual methods: trial division by small primes, Pollard's rho
+//! method, Pollard's p-1 method and Lenstra's elliptic curve method. It is intended for numbers
+//! up to a few hundred bits whose second largest prime factor has at most about 30 digits.
+//!
+//! All algorithms are deterministic.
+//!
+//! # Examples
+//!
+//! ```
+//! use ibig::{factor, ubig};
+//!
+//! let n = ubig!(1000000000000000000000000000001);
+//! let factors: Vec<_> = factor::factorize(&n).into_iter().collect();
+//! assert_eq!(
+//!     factors,
+//!     [
+//!         (ubig!(61), 1),
+//!         (ubig!(101), 1),
+//!         (ubig!(3541), 1),
+//!         (ubig!(9901), 1),
+//!         (ubig!(27961), 1),
+//!         (ubig!(4188901), 1),
+//!         (ubig!(39526741), 1)
+//!     ]
+//! );
+//! assert!(factor::is_probable_prime(&ubig!(39526741)));
+//! ```
+
+pub use ecm::ecm;
+pub use p_minus_1::pollard_p_minus_1;
+pub use prime::is_probable_prime;
+pub use rho::pollard_rho;
+pub use trial::trial_division;
+
+use crate::{math, sieve, ubig::UBig};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+mod ecm;
+mod p_minus_1;
+mod prime;
+mod rho;
+mod trial;
+
+/// Primes below this bound are found by trial division.
+const TRIAL_DIVISION_BOUND: usize = 1 << 12;
+
+/// Iterations of Pollard's rho method before trying other methods.
+const RHO_ITERATIONS: usize = 1 << 16;
+
+/// Stage 1 bound for Pollard's p-1 method.
+const P_MINUS_1_BOUND: usize = 100000;
+
+/// Stage 1 bounds and numbers of curves for the elliptic curve method.
+///
+/// Each level is suitable for factors up to 15, 20, 25, 30 digits. After the last level, the
+/// bound and the number of curves keep growing.
+const ECM_LEVELS: [(usize, usize); 4] = [(2000, 25), (11000, 90), (50000, 300), (250000, 700)];
+
+/// Prime factorization.
+///
+/// Returns a map from prime factors to their multiplicities. The factorization of 1 is empty.
+///
+/// # Example
+///
+/// ```
+/// # use ibig::{factor, ubig};
+/// let factors = factor::factorize(&ubig!(360));
+/// assert_eq!(factors.len(), 3);
+/// assert_eq!(factors[&ubig!(2)], 3);
+/// assert_eq!(factors[&ubig!(3)], 2);
+/// assert_eq!(factors[&ubig!(5)], 1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+pub fn factorize(n: &UBig) -> BTreeMap<UBig, usize> {
+    if *n == UBig::from_word(0) {
+        panic!("factorize(0)");
+    }
+    let (mut factors, cofactor) = trial_division(n, TRIAL_DIVISION_BOUND);
+
+    // Composite numbers to factor, with multiplicities. All their prime factors are at least
+    // TRIAL_DIVISION_BOUND.
+    let mut stack = vec![(cofactor, 1)];
+    while let Some((m, multiplicity)) = stack.pop() {
+        if m == UBig::from_word(1) {
+            continue;
+        }
+        if is_prime_after_trial_division(&m) {
+            *factors.entry(m).or_insert(0) += multiplicity;
+            continue;
+        }
+        if let Some((root, exponent)) = perfect_power(&m) {
+            stack.push((root, multiplicity * exponent));
+            continue;
+        }
+        let d = find_factor(&m);
+        let q = &m / &d;
+        stack.push((d, multiplicity));
+        stack.push((q, multiplicity));
+    }
+    factors
+}
+
+/// Primality test for n with no prime factors below TRIAL_DIVISION_BOUND.
+fn is_prime_after_trial_division(n: &UBig) -> bool {
+    *n < UBig::from(TRIAL_DIVISION_BOUND * TRIAL_DIVISION_BOUND) || is_probable_prime(n)
+}
+
+/// Find (root, exponent) such that n = root^exponent, exponent > 1.
+///
+/// n must have no prime factors below TRIAL_DIVISION_BOUND.
+fn perfect_power(n: &UBig) -> Option<(UBig, usize)> {
+    let max_exponent = n.bit_len() / (math::bit_len(TRIAL_DIVISION_BOUND) as usize - 1);
+    for exponent in sieve::primes_up_to(max_exponent) {
+        let root = n.nth_root(exponent);
+        if root.pow(exponent) == *n {
+            return Some((root, exponent));
+        }
+    }
+    None
+}
+
+/// Find a proper divisor of a composite number that is not a perfect power.
+fn find_factor(n: &UBig) -> UBig {
+    if let Some(d) = pollard_rho(n, RHO_ITERATIONS) {
+        return d;
+    }
+    if let Some(d) = pollard_p_minus_1(n, P_MINUS_1_BOUND) {
+        return d;
+    }
+    let mut first_curve = 0;
+    let levels = ECM_LEVELS
+        .iter()
+        .cloned()
+        .chain((1..).map(|i| (ECM_LEVELS[3].0 << (2 * i), ECM_LEVELS[3].1 << i)));
+    for (bound, curves) in levels {
+        if let Some(d) = ecm::ecm_curves(n, bound, first_curve..first_curve + curves) {
+            return d;
+        }
+        first_curve += curves;
+    }
+    unreachable!()
+}
+
+/// Number of `usize` products in each stage 1 exponent.
+const STAGE_1_EXPONENT_CHUNK: usize = 64;
+
+/// The largest powers of all primes that don't exceed `bound`, grouped into products of moderate
+/// size.
+///
+/// The product of these is the exponent for stage 1 of the p-1 method and the elliptic curve
+/// method. It is split so that large bounds don't create numbers that are too large.
+fn stage_1_exponents(bound: usize) -> Vec<UBig> {
+    let mut products = Vec::new();
+    let mut product: usize = 1;
+    for p in sieve::primes_up_to(bound) {
+        let mut power = p;
+        while power <= bound / p {
+            power *= p;
+        }
+        match product.checked_mul(power) {
+            Some(x) => product = x,
+            None => {
+                products.push(product);
+                product = power;
+            }
+        }
+    }
+    products.push(product);
+    products
+        .chunks(STAGE_1_EXPONENT_CHUNK)
+        .map(|chunk| chunk.iter().map(|&x| UBig::from(x)).product())
+        .collect()
+}
@@ -0,0 +1,74 @@
+//! Pollard's p-1 method.
+
+use crate::{factor, modular::ModuloRing, sieve, ubig::UBig};
+use alloc::vec::Vec;
+
+/// Stage 2 bound as a multiple of the stage 1 bound.
+pub(crate) const STAGE_2_RATIO: usize = 50;
+
+/// Pollard's p-1 method.
+///
+/// Finds prime factors `p` such that all prime power factors of `p-1` are at most `bound`,
+/// except for possibly one prime factor up to `50 * bound`.
+///
+/// Returns a proper divisor `1 < d < n` of `n`, or `None` if none was found.
+///
+/// # Example
+///
+/// ```
+/// # use ibig::{factor, ubig};
+/// // p - 1 = 2^3 * 3^2 * 97 * 26209 * 32779 * 166667
+/// let p = ubig!(1000000000000000009);
+/// // q - 1 = 2 * 3 * 17 * 131 * 1427 * 52445056723
+/// let q = ubig!(1000000000000000003);
+/// let n = &p * &q;
+/// assert_eq!(factor::pollard_p_minus_1(&n, 40000), Some(p));
+/// assert_eq!(factor::pollard_p_minus_1(&n, 1000), None);
+/// ```
+pub fn pollard_p_minus_1(n: &UBig, bound: usize) -> Option<UBig> {
+    if *n <= UBig::from_word(3) {
+        return None;
+    }
+    if n % 2u8 == 0 {
+        return Some(UBig::from_word(2));
+    }
+    let one = UBig::from_word(1);
+    let ring = ModuloRing::new(n);
+    let one_mod = ring.from(1u8);
+
+    // Stage 1: a = 3^E where E is the product of the largest prime powers up to bound.
+    // Base 2 would be useless for factors of 2^k - 1.
+    let mut a = ring.from(3u8);
+    for exponent in factor::stage_1_exponents(bound) {
+        a = a.pow(&exponent);
+    }
+    let g = (&a - &one_mod).residue().gcd(n);
+    if g == *n {
+        return None;
+    }
+    if g != one {
+        return Some(g);
+    }
+
+    // Stage 2: product of a^q - 1 for primes q in (bound, STAGE_2_RATIO * bound].
+    let primes = sieve::primes_up_to(bound.saturating_mul(STAGE_2_RATIO));
+    let first = primes.iter().position(|&q| q > bound)?;
+    // a^gap for even gaps between consecutive primes, indexed by gap / 2.
+    let mut a_gaps = Vec::new();
+    let mut b = a.pow(&UBig::from(primes[first]));
+    let mut product = &b - &one_mod;
+    for i in first + 1..primes.len() {
+        let half_gap = (primes[i] - primes[i - 1]) / 2;
+        while a_gaps.len() <= half_gap {
+            a_gaps.push(a.pow(&UBig::from(2 * a_gaps.len())));
+        }
+        b *= &a_gaps[half_gap];
+        product *= &b - &one_mod;
+    }
+    let g = product.residue().gcd(n);
+    if g == one || g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
@@ -0,0 +1,148 @@
+//! Primality testing.
+
+use crate::{modular::ModuloRing, recurrence, ubig::UBig};
+
+/// Primes checked by trial division before the probabilistic tests.
+const SMALL_PRIMES: [u8; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Probabilistic primality test.
+///
+/// Uses the Baillie-PSW test: a strong Fermat test to base 2 and a strong Lucas test. There are
+/// no known composite numbers that pass it, and none exist below 2^64.
+///
+/// # Example
+///
+/// ```
+/// # use ibig::{factor, ubig};
+/// assert!(factor::is_probable_prime(&ubig!(1000000007)));
+/// assert!(!factor::is_probable_prime(&ubig!(1000000011)));
+/// assert!(factor::is_probable_prime(&((ubig!(1) << 127) - ubig!(1))));
+/// ```
+pub fn is_probable_prime(n: &UBig) -> bool {
+    for &p in SMALL_PRIMES.iter() {
+        if *n == UBig::from(p) {
+            return true;
+        }
+        if n % p == 0 {
+            return false;
+        }
+    }
+    if *n < UBig::from_word(97 * 97) {
+        return *n > UBig::from_word(1);
+    }
+    is_strong_probable_prime_base_2(n) && is_strong_lucas_probable_prime(n)
+}
+
+/// Strong Fermat test to base 2 for an odd number n > 2.
+fn is_strong_probable_prime_base_2(n: &UBig) -> bool {
+    let ring = ModuloRing::new(n);
+    let one = ring.from(1u8);
+    let minus_one = -&one;
+    // n - 1 = d * 2^s
+    let n_minus_1 = n - UBig::from_word(1);
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let mut x = ring.from(2u8).pow(&(n_minus_1 >> s));
+    if x == one || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = &x * &x;
+        if x == minus_one {
+            return true;
+        }
+    }
+    false
+}
+
+/// Strong Lucas test with Selfridge's parameters, for an odd number n > 97^2 with no small prime
+/// factors.
+fn is_strong_lucas_probable_prime(n: &UBig) -> bool {
+    // A square never has jacobi(d, n) = -1.
+    let root = n.sqrt();
+    if &root * &root == *n {
+        return false;
+    }
+    // First d in 5, -7, 9, -11, ... with jacobi(d, n) = -1.
+    let mut d: i64 = 5;
+    loop {
+        match jacobi(d, n) {
+            -1 => break,
+            // gcd(d, n) > 1 and |d| < n.
+            0 => return false,
+            _ => d = if d > 0 { -d - 2 } else { -d + 2 },
+        }
+    }
+
+    // p = 1, q = (1 - d) / 4
+    let ring = ModuloRing::new(n);
+    let p = ring.from(1u8);
+    let q = ring.from((1 - d) / 4);
+    let zero = ring.from(0u8);
+    // n + 1 = k * 2^s
+    let n_plus_1 = n + UBig::from_word(1);
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let k = n_plus_1 >> s;
+    let (u, u_next) = recurrence::lucas_u_pair(&k, &p, &q, zero.clone(), ring.from(1u8));
+    // V(k) = 2 U(k+1) - p U(k)
+    let mut v = &u_next + &u_next - &p * &u;
+    if u == zero || v == zero {
+        return true;
+    }
+    let mut q_power = q.pow(&k);
+    for _ in 1..s {
+        // V(2k) = V(k)^2 - 2 q^k
+        v = &v * &v - &q_power - &q_power;
+        if v == zero {
+            return true;
+        }
+        q_power = &q_power * &q_power;
+    }
+    false
+}
+
+/// Jacobi symbol (a / n) for odd n > |a|.
+fn jacobi(a: i64, n: &UBig) -> i8 {
+    let mut result = 1;
+    let n_mod_8 = n % 8u8;
+    // (-1 / n) = (-1)^((n-1)/2)
+    if a < 0 && n_mod_8 % 4 == 3 {
+        result = -result;
+    }
+    let mut a = a.abs() as u64;
+    // (2 / n) = (-1)^((n^2-1)/8)
+    let twos = a.trailing_zeros();
+    a >>= twos;
+    if twos % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+        result = -result;
+    }
+    // Quadratic reciprocity: (a / n) = (n / a) (-1)^((a-1)/2 (n-1)/2)
+    if a % 4 == 3 && n_mod_8 % 4 == 3 {
+        result = -result;
+    }
+    result * jacobi_small(n % a, a)
+}
+
+/// Jacobi symbol (a / n) for odd n.
+fn jacobi_small(mut a: u64, mut n: u64) -> i8 {
+    let mut result = 1;
+    a %= n;
+    while a != 0 {
+        let twos = a.trailing_zeros();
+        a >>= twos;
+        if twos % 2 == 1 && (n % 8 == 3 || n % 8 == 5) {
+            result = -result;
+        }
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        core::mem::swap(&mut a, &mut n);
+        a %= n;
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
@@ -0,0 +1,103 @@
+//! Pollard's rho method.
+
+use crate::{
+    modular::{Modulo, ModuloRing},
+    ubig::UBig,
+};
+
+/// Number of steps between gcd computations.
+const GCD_INTERVAL: usize = 128;
+
+/// Pollard's rho method, with Brent's cycle detection.
+///
+/// Returns a proper divisor `1 < d < n` of `n`, or `None` if none was found within about
+/// `max_iterations` steps. Finds a prime factor `p` in about `sqrt(p)` steps.
+///
+/// # Example
+///
+/// ```
+/// # use ibig::{factor, ubig};
+/// let n = ubig!(1000003) * ubig!(1000033);
+/// let d = factor::pollard_rho(&n, 100000).unwrap();
+/// assert!(d == ubig!(1000003) || d == ubig!(1000033));
+/// assert_eq!(factor::pollard_rho(&ubig!(1000003), 100000), None);
+/// ```
+pub fn pollard_rho(n: &UBig, max_iterations: usize) -> Option<UBig> {
+    if *n <= UBig::from_word(3) {
+        return None;
+    }
+    if n % 2u8 == 0 {
+        return Some(UBig::from_word(2));
+    }
+    let ring = ModuloRing::new(n);
+    let mut iterations = 0;
+    // If the sequence x -> x^2 + c cycles modulo all prime factors at once, try another c.
+    for c in 1.. {
+        match brent(n, &ring, &ring.from(c), max_iterations - iterations) {
+            Ok(d) => return Some(d),
+            Err(used) => iterations += used,
+        }
+        if iterations >= max_iterations {
+            break;
+        }
+    }
+    None
+}
+
+/// Brent's variant of the rho method for the sequence x -> x^2 + c.
+///
+/// Returns a proper divisor, or the number of iterations used.
+fn brent<'a>(
+    n: &UBig,
+    ring: &'a ModuloRing,
+    c: &Modulo<'a>,
+    max_iterations: usize,
+) -> Result<UBig, usize> {
+    let one = UBig::from_word(1);
+    let step = |x: &Modulo<'a>| x * x + c;
+    let mut y = ring.from(2u8);
+    let mut x = y.clone();
+    let mut saved_y = y.clone();
+    let mut product = ring.from(1u8);
+    let mut iterations = 0;
+    let mut g = one.clone();
+    // x = x_(r-1), y = x_(r-1+k). Compare y with x for k in 1..=r.
+    let mut r = 1;
+    while g == one {
+        x = y.clone();
+        for _ in 0..r {
+            y = step(&y);
+        }
+        let mut k = 0;
+        while k < r && g == one {
+            if iterations >= max_iterations {
+                return Err(iterations);
+            }
+            saved_y = y.clone();
+            let batch = GCD_INTERVAL.min(r - k);
+            for _ in 0..batch {
+                y = step(&y);
+                product *= &x - &y;
+            }
+            iterations += batch;
+            g = product.residue().gcd(n);
+            k += batch;
+        }
+        r *= 2;
+    }
+    if g == *n {
+        // The batch went too far, redo it one step at a time.
+        loop {
+            saved_y = step(&saved_y);
+            g = (&x - &saved_y).residue().gcd(n);
+            if g != one {
+                break;
+            }
+        }
+    }
+    if g == *n {
+        Err(iterations)
+    } else {
+        Ok(g)
+    }
+}
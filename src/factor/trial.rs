@@ -0,0 +1,65 @@
+//! Trial division.
+
+use crate::{sieve, ubig::UBig};
+use alloc::collections::BTreeMap;
+
+/// Trial division by all primes `p <= bound`.
+///
+/// Returns the prime factors found, with multiplicities, and the remaining cofactor. The cofactor
+/// has no prime factors `p <= bound`. If the cofactor is 1, the factorization is complete.
+///
+/// # Example
+///
+/// ```
+/// # use ibig::{factor, ubig};
+/// let (factors, cofactor) = factor::trial_division(&ubig!(1001000), 10);
+/// assert_eq!(factors.into_iter().collect::<Vec<_>>(), [(ubig!(2), 3), (ubig!(5), 3), (ubig!(7), 1)]);
+/// assert_eq!(cofactor, ubig!(143));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+pub fn trial_division(n: &UBig, bound: usize) -> (BTreeMap<UBig, usize>, UBig) {
+    if *n == UBig::from_word(0) {
+        panic!("trial_division(0)");
+    }
+    let mut factors = BTreeMap::new();
+    let mut n = n.clone();
+    let primes = sieve::primes_up_to(bound);
+    let mut start = 0;
+    while start < primes.len() && n != UBig::from_word(1) {
+        // Take as many primes as fit in a usize product, so that one long division checks them all.
+        let mut end = start;
+        let mut product: usize = 1;
+        while end < primes.len() {
+            match product.checked_mul(primes[end]) {
+                Some(x) => product = x,
+                None => break,
+            }
+            end += 1;
+        }
+        let remainder = &n % product;
+        for &p in &primes[start..end] {
+            if remainder % p == 0 {
+                let mut multiplicity = 0;
+                while &n % p == 0 {
+                    n /= p;
+                    multiplicity += 1;
+                }
+                factors.insert(UBig::from(p), multiplicity);
+            }
+        }
+        // n has no prime factors <= last, so if it is composite it is at least (last + 1)^2.
+        let last = primes[end - 1];
+        if n < UBig::from(last + 1).pow(2) {
+            if n != UBig::from_word(1) {
+                factors.insert(n, 1);
+                n = UBig::from_word(1);
+            }
+            break;
+        }
+        start = end;
+    }
+    (factors, n)
+}
@@ -216,6 +216,10 @@ impl PreparedLarge {
     }
 
     /// Write (digits_per_word * CHUNK_LEN) << i digits.
+    ///
+    /// Each radix power is divided by only a few times at the sizes where a precomputed
+    /// reciprocal (`div::newton::Reciprocal`) would be used, which isn't enough to pay for
+    /// computing it, so regular division is used.
     fn write_big_chunk(&self, digit_writer: &mut DigitWriter, i: usize, x: UBig) -> fmt::Result {
         if i == 0 {
             self.write_chunk(digit_writer, x)
@@ -0,0 +1,144 @@
+//! Binary gcd algorithm.
+
+use crate::{ibig::IBig, ops::DivRem, ubig::UBig};
+use core::mem;
+
+/// Greatest common divisor.
+///
+/// One step of the Euclidean algorithm, followed by the binary algorithm.
+pub(crate) fn gcd(lhs: &UBig, rhs: &UBig) -> UBig {
+    let (mut a, mut b) = (lhs.clone(), rhs.clone());
+
+    let zeros = match (a.trailing_zeros(), b.trailing_zeros()) {
+        (None, None) => panic!("gcd(0, 0)"),
+        (None, Some(_)) => return b,
+        (Some(_), None) => return a,
+        (Some(a_zeros), Some(b_zeros)) => {
+            a >>= a_zeros;
+            b >>= b_zeros;
+            a_zeros.min(b_zeros)
+        }
+    };
+
+    // One round of Euclidean algorithm.
+    if a < b {
+        mem::swap(&mut a, &mut b);
+    }
+    a %= &b;
+
+    // Binary algorithm.
+    loop {
+        // b is odd
+        match a.trailing_zeros() {
+            None => break,
+            Some(a_zeros) => a >>= a_zeros,
+        }
+        // a is odd
+
+        if a < b {
+            mem::swap(&mut a, &mut b);
+        }
+        a -= &b;
+    }
+
+    b << zeros
+}
+
+/// Greatest common divisor and the Bézout coefficients.
+///
+/// Same guarantees as `UBig::extended_gcd`.
+pub(crate) fn extended_gcd(lhs: &UBig, rhs: &UBig) -> (UBig, IBig, IBig) {
+    let zeros = match (lhs.trailing_zeros(), rhs.trailing_zeros()) {
+        (None, None) => panic!("extended_gcd(0, 0)"),
+        (None, Some(_)) => return (rhs.clone(), 0u8.into(), 1u8.into()),
+        (Some(_), None) => return (lhs.clone(), 1u8.into(), 0u8.into()),
+        (Some(a_zeros), Some(b_zeros)) => a_zeros.min(b_zeros),
+    };
+
+    let u = lhs >> zeros;
+    let v = rhs >> zeros;
+    let mut a;
+    let mut b;
+    let mut ax;
+    let mut ay;
+    let mut bx;
+    let mut by;
+
+    // Invariants:
+    // gcd(a, b) == gcd(u, v)
+    // a = ax * u - ay * v
+    // b = bx * u - by * v
+    // ax, bx <= v
+    // ay, by <= u
+
+    // One round of Euclidean algorithm.
+    if u <= v {
+        let (q, r) = (&v).div_rem(&u);
+        // u = 1 * u - 0 * v
+        // r = v - q * u = (v-q) * u - (u-1) * v
+        a = u.clone();
+        ax = UBig::from_word(1);
+        ay = UBig::from_word(0);
+        b = r;
+        bx = &v - q;
+        by = &u - UBig::from_word(1);
+    } else {
+        let (q, r) = (&u).div_rem(&v);
+        // v = 0 * u + 1 * v = v * u - (u-1) * v
+        // r = 1 * u - q * v
+        a = v.clone();
+        ax = v.clone();
+        ay = &u - UBig::from_word(1);
+
+        b = r;
+        bx = UBig::from_word(1);
+        by = q;
+    }
+
+    // At least one of a and b is odd (because gcd(u, v) is odd). Make b odd.
+    if &b & 1u8 == 0u8 {
+        mem::swap(&mut a, &mut b);
+        mem::swap(&mut ax, &mut bx);
+        mem::swap(&mut ay, &mut by);
+    }
+
+    // Binary algorithm.
+    while a != UBig::from_word(0) {
+        // b is odd
+        while &a & 1u8 == 0u8 {
+            // a is even
+            if &ax & 1u8 != 0u8 || &ay & 1u8 != 0u8 {
+                ax += &v;
+                ay += &u;
+            }
+            // Now ax, ay are even.
+            a >>= 1usize;
+            ax >>= 1usize;
+            ay >>= 1usize;
+            // Again ax <= v, bx <= u.
+        }
+        // Both a and b are odd.
+        if a < b {
+            mem::swap(&mut a, &mut b);
+            mem::swap(&mut ax, &mut bx);
+            mem::swap(&mut ay, &mut by);
+        }
+        a -= &b;
+        if ax < bx {
+            ax += &v;
+            ay += &u;
+        }
+        ax -= &bx;
+        ay -= &by;
+        // ax >= 0 in both cases
+        // ax <= v in both cases
+        // ax * u - ay * v = a
+        // ay * v = ax * u - a <= v * u - 0
+        // ay <= u
+        // After one round Euclidean, and at least one subtraction, a < min(u,v).
+        // ay * v = ax * u - a >= -a > -min(u,v) >= -v
+        // ay >= 0
+    }
+
+    (b << zeros, IBig::from(bx), -IBig::from(by))
+}
@@ -0,0 +1,67 @@
+//! Half-GCD: subquadratic computation of Euclidean quotients.
+
+use crate::{
+    gcd::{lehmer, matrix::Matrix},
+    ops::DivRem,
+    ubig::UBig,
+};
+
+/// If the larger number has at least this many bits, use the recursive algorithm rather than
+/// Lehmer steps.
+pub(crate) const MIN_BITS: usize = 2048;
+
+/// Extra bits used beyond those that are needed in principle.
+///
+/// Quotients computed from the top bits are correct for the whole numbers unless the remainders
+/// come within about this many bits of the size of the matrix entries.
+pub(crate) const MARGIN_BITS: usize = 64;
+
+/// Find the initial quotients of the Euclidean algorithm for (a, b) until the remainder is
+/// less than 2^s.
+///
+/// a >= b, s >= (a.bit_len() - MARGIN_BITS) / 2.
+///
+/// The entries of the matrix are at most about 2^(a.bit_len() - s).
+pub(crate) fn matrix(mut a: UBig, mut b: UBig, mut s: usize) -> Matrix {
+    // The matrix entries will be at most 2^d, d = n - s. Quotients computed only from the bits
+    // above 2s - n - MARGIN_BITS are correct unless the remainders come within
+    // MARGIN_BITS of 2^d, which is very unlikely.
+    //
+    // The final remainder is then verified anyway by the caller.
+    let shift = (2 * s).saturating_sub(a.bit_len() + MARGIN_BITS);
+    a >>= shift;
+    b >>= shift;
+    s -= shift;
+
+    // Invariant: (original a, b) = result * (a, b)
+    let mut result = Matrix::identity();
+    while b.bit_len() > s {
+        let n = a.bit_len();
+        let step = if n >= MIN_BITS {
+            // Reduce halfway using the top bits.
+            matrix(a.clone(), b.clone(), n - (n - s) / 2)
+        } else {
+            lehmer::matrix(&a, &b, Some(s))
+        };
+        let reduced = if step.is_identity() {
+            None
+        } else {
+            step.apply_inverse(&a, &b)
+        };
+        match reduced {
+            Some((new_a, new_b)) => {
+                a = new_a;
+                b = new_b;
+                result = result.mul(&step);
+            }
+            None => {
+                // One Euclidean step.
+                let (q, r) = (&a).div_rem(&b);
+                a = b;
+                b = r;
+                result.mul_quotient(&q);
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,114 @@
+//! Lehmer's step: Euclidean quotients from the top words.
+
+use crate::{
+    arch::word::{DoubleWord, Word},
+    gcd::matrix::Matrix,
+    primitive::{double_word, extend_word, WORD_BITS_USIZE},
+    ubig::{Repr, UBig},
+};
+
+/// Find the initial quotients of the Euclidean algorithm for (a, b) looking only at the top two
+/// words of a.
+///
+/// a >= b.
+///
+/// If `min_bits` is `Some(s)`, the remainders are kept at least 2^s.
+///
+/// Returns the identity matrix if no quotient can be determined.
+///
+/// Jebelean, "Improving the multiprecision Euclidean algorithm", DISCO '93.
+pub(crate) fn matrix(a: &UBig, b: &UBig, min_bits: Option<usize>) -> Matrix {
+    let shift = a.bit_len().saturating_sub(2 * WORD_BITS_USIZE);
+
+    // Let a = A * 2^shift + a_low, b = B * 2^shift + b_low.
+    //
+    // Run the Euclidean algorithm on (A, B): r_i = u_i A + v_i B.
+    // Then the real remainders are R_i = u_i a + v_i b = r_i 2^shift + (u_i a_low + v_i b_low).
+    //
+    // The signs of u_i and v_i alternate, so |u_i a_low + v_i b_low| < 2^shift max(|u_i|, |v_i|).
+    // Hence the quotient q_i is correct, that is 0 <= R_(i+1) < R_i, if:
+    // r_(i+1) >= max(|u_(i+1)|, |v_(i+1)|)
+    // r_i - r_(i+1) >= max(|u_i - u_(i+1)|, |v_i - v_(i+1)|)
+    //
+    // Additionally R_(i+1) >= 2^s if r_(i+1) - max(|u_(i+1)|, |v_(i+1)|) >= 2^(s - shift).
+    let min_rem: DoubleWord = match min_bits {
+        None => 0,
+        Some(s) if s <= shift => 1,
+        Some(s) if s - shift < 2 * WORD_BITS_USIZE => 1 << (s - shift),
+        Some(_) => return Matrix::identity(),
+    };
+
+    let mut r0 = top_bits(a, shift);
+    let mut r1 = top_bits(b, shift);
+    // Absolute values of the coefficients.
+    let (mut u0, mut v0, mut u1, mut v1): (Word, Word, Word, Word) = (1, 0, 0, 1);
+    let mut odd = false;
+
+    while r1 != 0 {
+        let (q, r2) = if r0 - r1 < r1 {
+            (1, r0 - r1)
+        } else {
+            (r0 / r1, r0 % r1)
+        };
+        let u2 = match next_coefficient(u0, u1, q) {
+            Some(u2) => u2,
+            None => break,
+        };
+        let v2 = match next_coefficient(v0, v1, q) {
+            Some(v2) => v2,
+            None => break,
+        };
+        if r2 < extend_word(u2.max(v2)) + min_rem
+            || r1 - r2 < extend_word(u1) + extend_word(u2)
+            || r1 - r2 < extend_word(v1) + extend_word(v2)
+        {
+            break;
+        }
+        r0 = r1;
+        r1 = r2;
+        u0 = u1;
+        v0 = v1;
+        u1 = u2;
+        v1 = v2;
+        odd = !odd;
+    }
+
+    // (a, b) = [[|v_(k+1)|, |v_k|], [|u_(k+1)|, |u_k|]] (R_k, R_(k+1))
+    Matrix::from_words(v1, v0, u1, u0, odd)
+}
+
+/// |c_(i+1)| = |c_(i-1)| + q |c_i| if it fits in a Word.
+fn next_coefficient(c0: Word, c1: Word, q: DoubleWord) -> Option<Word> {
+    let c2 = q
+        .checked_mul(extend_word(c1))?
+        .checked_add(extend_word(c0))?;
+    if c2 > extend_word(Word::MAX) {
+        None
+    } else {
+        Some(c2 as Word)
+    }
+}
+
+/// x >> shift, which must fit in a DoubleWord.
+fn top_bits(x: &UBig, shift: usize) -> DoubleWord {
+    match x.repr() {
+        Repr::Small(word) => {
+            if shift < WORD_BITS_USIZE {
+                extend_word(*word >> shift)
+            } else {
+                0
+            }
+        }
+        Repr::Large(words) => {
+            let word = |i: usize| words.get(i).copied().unwrap_or(0);
+            let i = shift / WORD_BITS_USIZE;
+            let bit_shift = shift % WORD_BITS_USIZE;
+            let low = double_word(word(i), word(i + 1));
+            if bit_shift == 0 {
+                low
+            } else {
+                low >> bit_shift | extend_word(word(i + 2)) << (2 * WORD_BITS_USIZE - bit_shift)
+            }
+        }
+    }
+}
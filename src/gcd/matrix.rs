@@ -0,0 +1,113 @@
+//! Matrices of quotients of the Euclidean algorithm.
+
+use crate::{arch::word::Word, ibig::IBig, ubig::UBig};
+
+/// Product of matrices [[q, 1], [1, 0]] for a sequence of quotients q of the Euclidean algorithm.
+///
+/// If the quotients reduce (a, b) to (a', b'), then (a, b) = M (a', b').
+pub(crate) struct Matrix {
+    m: [[UBig; 2]; 2],
+    /// The determinant is -1 if the number of quotients is odd, 1 otherwise.
+    det_negative: bool,
+}
+
+impl Matrix {
+    /// No quotients.
+    pub(crate) fn identity() -> Matrix {
+        Matrix {
+            m: [
+                [UBig::from_word(1), UBig::from_word(0)],
+                [UBig::from_word(0), UBig::from_word(1)],
+            ],
+            det_negative: false,
+        }
+    }
+
+    /// Matrix [[m00, m01], [m10, m11]] with single word entries.
+    pub(crate) fn from_words(
+        m00: Word,
+        m01: Word,
+        m10: Word,
+        m11: Word,
+        det_negative: bool,
+    ) -> Matrix {
+        Matrix {
+            m: [
+                [UBig::from_word(m00), UBig::from_word(m01)],
+                [UBig::from_word(m10), UBig::from_word(m11)],
+            ],
+            det_negative,
+        }
+    }
+
+    /// Is this the identity matrix?
+    pub(crate) fn is_identity(&self) -> bool {
+        // Any non-empty product has m01 >= 1.
+        self.m[0][1] == UBig::from_word(0)
+    }
+
+    /// self * rhs
+    pub(crate) fn mul(&self, rhs: &Matrix) -> Matrix {
+        let a = &self.m;
+        let b = &rhs.m;
+        Matrix {
+            m: [
+                [
+                    &a[0][0] * &b[0][0] + &a[0][1] * &b[1][0],
+                    &a[0][0] * &b[0][1] + &a[0][1] * &b[1][1],
+                ],
+                [
+                    &a[1][0] * &b[0][0] + &a[1][1] * &b[1][0],
+                    &a[1][0] * &b[0][1] + &a[1][1] * &b[1][1],
+                ],
+            ],
+            det_negative: self.det_negative != rhs.det_negative,
+        }
+    }
+
+    /// self = self * [[q, 1], [1, 0]]
+    pub(crate) fn mul_quotient(&mut self, q: &UBig) {
+        for row in self.m.iter_mut() {
+            let new = &row[0] * q + &row[1];
+            row[1] = core::mem::replace(&mut row[0], new);
+        }
+        self.det_negative = !self.det_negative;
+    }
+
+    /// (a', b') = M^-1 (a, b)
+    ///
+    /// Returns `None` unless a' > b' >= 0, which is exactly when the quotients are
+    /// the initial quotients of the Euclidean algorithm for (a, b).
+    pub(crate) fn apply_inverse(&self, a: &UBig, b: &UBig) -> Option<(UBig, UBig)> {
+        // M^-1 = det * [[m11, -m01], [-m10, m00]]
+        let (a_pos, a_neg) = (&self.m[1][1] * a, &self.m[0][1] * b);
+        let (b_pos, b_neg) = (&self.m[0][0] * b, &self.m[1][0] * a);
+        let (a_pos, a_neg, b_pos, b_neg) = if self.det_negative {
+            (a_neg, a_pos, b_neg, b_pos)
+        } else {
+            (a_pos, a_neg, b_pos, b_neg)
+        };
+        if a_pos < a_neg || b_pos < b_neg {
+            return None;
+        }
+        let new_a = a_pos - a_neg;
+        let new_b = b_pos - b_neg;
+        if new_a > new_b {
+            Some((new_a, new_b))
+        } else {
+            None
+        }
+    }
+
+    /// (x', y') = M^-1 (x, y)
+    pub(crate) fn apply_inverse_signed(&self, x: &IBig, y: &IBig) -> (IBig, IBig) {
+        let m = &self.m;
+        let new_x = IBig::from(&m[1][1]) * x - IBig::from(&m[0][1]) * y;
+        let new_y = IBig::from(&m[0][0]) * y - IBig::from(&m[1][0]) * x;
+        if self.det_negative {
+            (-new_x, -new_y)
+        } else {
+            (new_x, new_y)
+        }
+    }
+}
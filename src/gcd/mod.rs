@@ -0,0 +1,364 @@
+
+//! Greatest common divisor.
+
+use crate::{
+    ibig::IBig,
+    ops::{DivRem, RemEuclid},
+    ubig::UBig,
+};
+use core::mem;
+
+mod binary;
+mod half;
+mod lehmer;
+mod matrix;
+
+/// If the smaller number has at most this many words, use the binary algorithm.
+const MAX_LEN_BINARY: usize = 2;
+
+impl UBig {
+    /// Greatest common divisor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(12).gcd(&ubig!(18)), ubig!(6));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `ubig!(0).gcd(&ubig!(0))` panics.
+    pub fn gcd(&self, rhs: &UBig) -> UBig {
+        if self.len().min(rhs.len()) <= MAX_LEN_BINARY {
+            return binary::gcd(self, rhs);
+        }
+        let (mut a, mut b) = if self >= rhs {
+            (self.clone(), rhs.clone())
+        } else {
+            (rhs.clone(), self.clone())
+        };
+        reduce(&mut a, &mut b, None);
+        binary::gcd(&a, &b)
+    }
+
+    /// Greatest common divisors and the Bézout coefficients.
+    ///
+    /// If `a.extended_gcd(&b) == (g, x, y)` then:
+    /// * `x * a + y * b == g`
+    /// * `abs(x) <= max(b, 1)`
+    /// * `abs(y) <= max(a, 1)`
+    ///
+    /// # Example
+    /// ```
+    /// # use ibig::{ubig, IBig, ops::UnsignedAbs};
+    /// let a = ubig!(12);
+    /// let b = ubig!(18);
+    /// let (g, x, y) = a.extended_gcd(&b);
+    /// assert_eq!(&a % &g, ubig!(0));
+    /// assert_eq!(&b % &g, ubig!(0));
+    /// assert_eq!(&x * IBig::from(&a) + &y * IBig::from(&b), IBig::from(g));
+    /// assert!(x.unsigned_abs() <= b);
+    /// assert!(y.unsigned_abs() <= a);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `ubig!(0).extended_gcd(&ubig!(0))` panics.
+    pub fn extended_gcd(&self, rhs: &UBig) -> (UBig, IBig, IBig) {
+        if self.len().min(rhs.len()) <= MAX_LEN_BINARY {
+            return binary::extended_gcd(self, rhs);
+        }
+        // Invariant:
+        // a = x * self (mod rhs)
+        // b = y * self (mod rhs)
+        let (mut a, mut b, mut cofactors) = if self >= rhs {
+            (self.clone(), rhs.clone(), (IBig::from(1u8), IBig::from(0u8)))
+        } else {
+            (rhs.clone(), self.clone(), (IBig::from(0u8), IBig::from(1u8)))
+        };
+        reduce(&mut a, &mut b, Some(&mut cofactors));
+        let (g, a_coeff, b_coeff) = binary::extended_gcd(&a, &b);
+        let (x, y) = cofactors;
+
+        // g = x * self (mod rhs)
+        let x = a_coeff * x + b_coeff * y;
+
+        // Choose x in the range [-rhs/g/2, rhs/g/2].
+        // Then abs(x) <= rhs / 2 and abs(y) <= (g + abs(x) * self) / rhs <= self.
+        let period = IBig::from(rhs / &g);
+        let mut x = x.rem_euclid(&period);
+        if IBig::from(2u8) * &x > period {
+            x -= period;
+        }
+        let y = (IBig::from(&g) - &x * IBig::from(self)) / IBig::from(rhs);
+        (g, x, y)
+    }
+
+    /// Greatest common divisor and the cofactors.
+    ///
+    /// Returns `(g, self / g, rhs / g)` where `g = self.gcd(rhs)`.
+    ///
+    /// The cofactors are computed by dividing by `g` after the gcd, so this costs a gcd and two
+    /// divisions. Division by a small `g` is linear, which is cheaper than tracking the cofactors
+    /// during the gcd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(
+    ///     ubig!(12).gcd_cofactors(&ubig!(18)),
+    ///     (ubig!(6), ubig!(2), ubig!(3))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `ubig!(0).gcd_cofactors(&ubig!(0))` panics.
+    pub fn gcd_cofactors(&self, rhs: &UBig) -> (UBig, UBig, UBig) {
+        let g = self.gcd(rhs);
+        let (self_cofactor, rhs_cofactor) = (self / &g, rhs / &g);
+        (g, self_cofactor, rhs_cofactor)
+    }
+
+    /// Least common multiple.
+    ///
+    /// The least common multiple of 0 and any number is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(12).lcm(&ubig!(18)), ubig!(36));
+    /// assert_eq!(ubig!(0).lcm(&ubig!(18)), ubig!(0));
+    /// ```
+    pub fn lcm(&self, rhs: &UBig) -> UBig {
+        if *self == UBig::from_word(0) || *rhs == UBig::from_word(0) {
+            return UBig::from_word(0);
+        }
+        self / self.gcd(rhs) * rhs
+    }
+
+    /// Greatest common divisor of a sequence of numbers.
+    ///
+    /// Zeros are ignored. Returns 0 if there are no non-zero numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let numbers = [ubig!(12), ubig!(0), ubig!(18), ubig!(30)];
+    /// assert_eq!(UBig::gcd_all(&numbers), ubig!(6));
+    /// assert_eq!(UBig::gcd_all(&[]), ubig!(0));
+    /// ```
+    pub fn gcd_all<'a, I>(numbers: I) -> UBig
+    where
+        I: IntoIterator<Item = &'a UBig>,
+    {
+        let mut result = UBig::from_word(0);
+        for x in numbers {
+            if result == UBig::from_word(1) {
+                break;
+            }
+            if *x == UBig::from_word(0) {
+                continue;
+            }
+            result = if result == UBig::from_word(0) {
+                x.clone()
+            } else {
+                result.gcd(x)
+            };
+        }
+        result
+    }
+
+    /// Least common multiple of a sequence of numbers.
+    ///
+    /// Returns 1 if there are no numbers, and 0 if any of them is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let numbers = [ubig!(4), ubig!(6), ubig!(10)];
+    /// assert_eq!(UBig::lcm_all(&numbers), ubig!(60));
+    /// assert_eq!(UBig::lcm_all(&[]), ubig!(1));
+    /// ```
+    pub fn lcm_all<'a, I>(numbers: I) -> UBig
+    where
+        I: IntoIterator<Item = &'a UBig>,
+    {
+        let mut result = UBig::from_word(1);
+        for x in numbers {
+            if *x == UBig::from_word(0) {
+                return UBig::from_word(0);
+            }
+            result = result.lcm(x);
+        }
+        result
+    }
+}
+
+/// Reduce (a, b), a >= b, preserving gcd(a, b), until b has at most MAX_LEN_BINARY words.
+///
+/// If `cofactors` are given, they are transformed together with (a, b).
+fn reduce(a: &mut UBig, b: &mut UBig, mut cofactors: Option<&mut (IBig, IBig)>) {
+    while b.len() > MAX_LEN_BINARY {
+        let n = a.bit_len();
+        let step = if n >= half::MIN_BITS {
+            half::matrix(a.clone(), b.clone(), (n + half::MARGIN_BITS) / 2)
+        } else {
+            lehmer::matrix(a, b, None)
+        };
+        let reduced = if step.is_identity() {
+            None
+        } else {
+            step.apply_inverse(a, b)
+        };
+        match reduced {
+            Some((new_a, new_b)) => {
+                *a = new_a;
+                *b = new_b;
+                if let Some((x, y)) = cofactors.as_deref_mut() {
+                    let (new_x, new_y) = step.apply_inverse_signed(x, y);
+                    *x = new_x;
+                    *y = new_y;
+                }
+            }
+            None => {
+                // One Euclidean step.
+                let (q, r) = (&*a).div_rem(&*b);
+                *a = mem::replace(b, r);
+                if let Some((x, y)) = cofactors.as_deref_mut() {
+                    let new_y = &*x - IBig::from(q) * &*y;
+                    *x = mem::replace(y, new_y);
+                }
+            }
+        }
+    }
+}
+
+impl IBig {
+    /// Greatest common divisor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-12).gcd(&ibig!(18)), ibig!(6));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `ibig!(0).gcd(&ibig!(0))` panics.
+    pub fn gcd(&self, rhs: &IBig) -> IBig {
+        self.magnitude().gcd(rhs.magnitude()).into()
+    }
+
+    /// Greatest common divisors and the Bézout coefficients.
+    ///
+    /// If `a.extended_gcd(&b) == (g, x, y)` then:
+    /// * `x * a + y * b == g`
+    /// * `abs(x) <= max(abs(b), 1)`
+    /// * `abs(y) <= max(abs(a), 1)`
+    ///
+    /// # Example
+    /// ```
+    /// # use ibig::{ibig, IBig, ops::Abs};
+    /// let a = ibig!(-12);
+    /// let b = ibig!(18);
+    /// let (g, x, y) = a.extended_gcd(&b);
+    /// assert_eq!(&a % &g, ibig!(0));
+    /// assert_eq!(&b % &g, ibig!(0));
+    /// assert_eq!(&x * &a + &y * &b, g);
+    /// assert!(x.abs() <= b.abs());
+    /// assert!(y.abs() <= a.abs());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `ibig!(0).extended_gcd(&ibig!(0))` panics.
+    pub fn extended_gcd(&self, rhs: &IBig) -> (IBig, IBig, IBig) {
+        let (g, x, y) = self.magnitude().extended_gcd(rhs.magnitude());
+        (IBig::from(g), self.sign() * x, rhs.sign() * y)
+    }
+
+    /// Greatest common divisor and the cofactors.
+    ///
+    /// Returns `(g, self / g, rhs / g)` where `g = self.gcd(rhs)`.
+    ///
+    /// See [UBig::gcd_cofactors] for the cost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(
+    ///     ibig!(-12).gcd_cofactors(&ibig!(18)),
+    ///     (ibig!(6), ibig!(-2), ibig!(3))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `ibig!(0).gcd_cofactors(&ibig!(0))` panics.
+    pub fn gcd_cofactors(&self, rhs: &IBig) -> (IBig, IBig, IBig) {
+        let (g, self_cofactor, rhs_cofactor) = self.magnitude().gcd_cofactors(rhs.magnitude());
+        (
+            IBig::from(g),
+            IBig::from_sign_magnitude(self.sign(), self_cofactor),
+            IBig::from_sign_magnitude(rhs.sign(), rhs_cofactor),
+        )
+    }
+
+    /// Least common multiple.
+    ///
+    /// The result is non-negative. The least common multiple of 0 and any number is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(-12).lcm(&ibig!(18)), ibig!(36));
+    /// ```
+    pub fn lcm(&self, rhs: &IBig) -> IBig {
+        self.magnitude().lcm(rhs.magnitude()).into()
+    }
+
+    /// Greatest common divisor of a sequence of numbers.
+    ///
+    /// The result is non-negative. Zeros are ignored. Returns 0 if there are no non-zero numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// let numbers = [ibig!(-12), ibig!(18), ibig!(30)];
+    /// assert_eq!(IBig::gcd_all(&numbers), ibig!(6));
+    /// ```
+    pub fn gcd_all<'a, I>(numbers: I) -> IBig
+    where
+        I: IntoIterator<Item = &'a IBig>,
+    {
+        UBig::gcd_all(numbers.into_iter().map(IBig::magnitude)).into()
+    }
+
+    /// Least common multiple of a sequence of numbers.
+    ///
+    /// The result is non-negative. Returns 1 if there are no numbers, and 0 if any of them is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// let numbers = [ibig!(-4), ibig!(6), ibig!(10)];
+    /// assert_eq!(IBig::lcm_all(&numbers), ibig!(60));
+    /// ```
+    pub fn lcm_all<'a, I>(numbers: I) -> IBig
+    where
+        I: IntoIterator<Item = &'a IBig>,
+    {
+        UBig::lcm_all(numbers.into_iter().map(IBig::magnitude)).into()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+//! Sums and products of iterators.
+
+use crate::{ibig::IBig, ubig::UBig};
+use alloc::vec::Vec;
+use core::{
+    iter::{Product, Sum},
+    ops::Mul,
+};
+
+impl Sum for UBig {
+    fn sum<I: Iterator<Item = UBig>>(iter: I) -> UBig {
+        iter.fold(UBig::from_word(0), |sum, x| sum + x)
+    }
+}
+
+impl<'a> Sum<&'a UBig> for UBig {
+    fn sum<I: Iterator<Item = &'a UBig>>(iter: I) -> UBig {
+        iter.fold(UBig::from_word(0), |sum, x| sum + x)
+    }
+}
+
+impl Sum for IBig {
+    fn sum<I: Iterator<Item = IBig>>(iter: I) -> IBig {
+        iter.fold(IBig::from(0u8), |sum, x| sum + x)
+    }
+}
+
+impl<'a> Sum<&'a IBig> for IBig {
+    fn sum<I: Iterator<Item = &'a IBig>>(iter: I) -> IBig {
+        iter.fold(IBig::from(0u8), |sum, x| sum + x)
+    }
+}
+
+impl Product for UBig {
+    fn product<I: Iterator<Item = UBig>>(iter: I) -> UBig {
+        product_tree(iter, UBig::from_word(1))
+    }
+}
+
+impl<'a> Product<&'a UBig> for UBig {
+    fn product<I: Iterator<Item = &'a UBig>>(iter: I) -> UBig {
+        product_tree(PairProducts(iter), UBig::from_word(1))
+    }
+}
+
+impl Product for IBig {
+    fn product<I: Iterator<Item = IBig>>(iter: I) -> IBig {
+        product_tree(iter, IBig::from(1u8))
+    }
+}
+
+impl<'a> Product<&'a IBig> for IBig {
+    fn product<I: Iterator<Item = &'a IBig>>(iter: I) -> IBig {
+        product_tree(PairProducts(iter), IBig::from(1u8))
+    }
+}
+
+/// Products of consecutive pairs of factors, and the last factor if the number of factors is odd.
+///
+/// The bottom layer of the product tree, multiplying references so that only the last factor is
+/// ever cloned.
+struct PairProducts<I>(I);
+
+impl<'a, T, I> Iterator for PairProducts<I>
+where
+    T: Clone + 'a,
+    &'a T: Mul<&'a T, Output = T>,
+    I: Iterator<Item = &'a T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let x = self.0.next()?;
+        match self.0.next() {
+            Some(y) => Some(x * y),
+            None => Some(x.clone()),
+        }
+    }
+}
+
+/// Product of all factors, multiplied in a balanced binary tree.
+///
+/// Multiplying one factor at a time into an accumulator takes quadratic time when there are many
+/// factors of similar size. The tree multiplies numbers of similar sizes instead, so that the
+/// fast multiplication algorithms apply.
+fn product_tree<T, I>(iter: I, one: T) -> T
+where
+    T: Mul<Output = T>,
+    I: Iterator<Item = T>,
+{
+    // Partial products with their levels: a product at level k has 2^k factors.
+    // The levels are strictly decreasing.
+    let mut stack: Vec<(T, u32)> = Vec::new();
+    for mut x in iter {
+        let mut level = 0;
+        loop {
+            match stack.last() {
+                Some((_, top_level)) if *top_level == level => {
+                    let (top, _) = stack.pop().unwrap();
+                    x = top * x;
+                    level += 1;
+                }
+                _ => break,
+            }
+        }
+        stack.push((x, level));
+    }
+    // Multiply the remaining partial products, smallest first.
+    stack
+        .into_iter()
+        .rev()
+        .fold(one, |product, (x, _)| x * product)
+}
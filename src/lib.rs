@@ -57,6 +57,7 @@
 //!
 //! * `std` (default): for `std::error::Error`.
 //! * `num-traits` (default): integral traits.
+//! * `num-integer`: `Integer` and `Roots` traits. Requires `num-traits`.
 //! * `rand` (default): random number generation.
 //! * `serde`: serialization and deserialization.
 
@@ -64,24 +65,29 @@
 
 extern crate alloc;
 
-pub use crate::{ibig::IBig, ubig::UBig};
+pub use crate::{arch::word::Word, divisor::Divisor, ibig::IBig, memory::Scratch, ubig::UBig};
 
 mod add;
 mod add_ops;
+pub mod allocator;
 mod arch;
 mod assert;
-mod bits;
+pub mod bits;
 mod buffer;
 mod cmp;
+mod combinatorics;
 mod convert;
 mod div;
 mod div_ops;
+mod divisor;
 pub mod error;
+pub mod factor;
 mod fast_divide;
 pub mod fmt;
 mod gcd;
 mod helper_macros;
 mod ibig;
+mod iter;
 mod macros;
 mod math;
 mod memory;
@@ -93,8 +99,11 @@ mod parse;
 mod pow;
 mod primitive;
 mod radix;
+mod recurrence;
+mod root;
 mod shift;
 mod shift_ops;
+mod sieve;
 mod sign;
 mod ubig;
 
@@ -104,5 +113,11 @@ pub mod rand;
 #[cfg(feature = "num-traits")]
 mod num_traits;
 
+#[cfg(all(feature = "num-integer", not(feature = "num-traits")))]
+compile_error!("feature `num-integer` requires feature `num-traits`");
+
+#[cfg(all(feature = "num-traits", feature = "num-integer"))]
+mod num_integer;
+
 #[cfg(feature = "serde")]
-mod serde;
\ No newline at end of file
+pub mod serde;
\ No newline at end of file
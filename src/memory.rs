@@ -1,10 +1,15 @@
 
 //! Memory allocation.
 
+use crate::allocator;
 use alloc::alloc::Layout;
-use core::{marker::PhantomData, mem, slice};
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    mem, slice,
+};
 
-/// Chunk of memory directly allocated from the global allocator.
+/// Chunk of memory directly allocated from the allocator.
 pub(crate) struct MemoryAllocation {
     layout: Layout,
     start: *mut u8,
@@ -30,7 +35,7 @@ impl MemoryAllocation {
             panic_out_of_memory()
         } else {
             // Safe because size is non-zero.
-            let ptr = unsafe { alloc::alloc::alloc(layout) };
+            let ptr = unsafe { allocator::alloc(layout) };
             if ptr.is_null() {
                 panic_out_of_memory();
             }
@@ -55,11 +60,71 @@ impl Drop for MemoryAllocation {
     fn drop(&mut self) {
         if self.layout.size() != 0 {
             // Safe because the memory was allocated with the same layout.
-            unsafe { alloc::alloc::dealloc(self.start, self.layout) };
+            unsafe { allocator::dealloc(self.start, self.layout) };
         }
     }
 }
 
+/// Reusable scratch memory for arithmetic operations.
+///
+/// Operations such as [UBig::mul_into](crate::UBig::mul_into) need temporary memory. Passing the
+/// same `Scratch` to repeated operations avoids allocating it every time. It grows as needed and
+/// keeps its largest size.
+///
+/// # Example
+///
+/// ```
+/// # use ibig::{ubig, Scratch, UBig};
+/// let mut scratch = Scratch::new();
+/// let mut product = UBig::default();
+/// let a = ubig!(1) << 1000;
+/// for i in 0..10 {
+///     UBig::mul_into(&mut product, &a, &(ubig!(1) << i), &mut scratch);
+///     assert_eq!(product, ubig!(1) << (1000 + i));
+/// }
+/// ```
+pub struct Scratch {
+    allocation: MemoryAllocation,
+}
+
+// Safe because the allocation is owned and only accessed through `&mut Scratch`.
+unsafe impl Send for Scratch {}
+unsafe impl Sync for Scratch {}
+
+impl Scratch {
+    /// Empty scratch space. Nothing is allocated until it is used.
+    #[inline]
+    pub fn new() -> Scratch {
+        Scratch {
+            allocation: MemoryAllocation::new(zero_layout()),
+        }
+    }
+
+    /// Get memory of at least a given layout, growing if necessary.
+    pub(crate) fn memory(&mut self, layout: Layout) -> Memory<'_> {
+        let current = self.allocation.layout;
+        if layout.size() > current.size() || layout.align() > current.align() {
+            self.allocation = MemoryAllocation::new(max_layout(current, layout));
+        }
+        self.allocation.memory()
+    }
+}
+
+impl Default for Scratch {
+    #[inline]
+    fn default() -> Scratch {
+        Scratch::new()
+    }
+}
+
+impl Debug for Scratch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Scratch")
+            .field("bytes", &self.allocation.layout.size())
+            .finish()
+    }
+}
+
 impl Memory<'_> {
     /// Allocate a slice with a given value.
     ///
@@ -5,14 +5,48 @@ use crate::{
     add, cmp,
     modular::{
         modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
-        modulo_ring::ModuloRingSmall,
+        modulo_ring::{ModuloRing, ModuloRingSmall},
     },
 };
 use core::{
+    borrow::Borrow,
     cmp::Ordering,
     ops::{Add, AddAssign, Neg, Sub, SubAssign},
 };
 
+impl ModuloRing {
+    /// Sum of elements of the ring.
+    ///
+    /// [Modulo] values can't implement [Sum](core::iter::Sum) because the sum of no elements
+    /// would need to know the ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{modular::ModuloRing, ubig};
+    /// let ring = ModuloRing::new(&ubig!(10));
+    /// let v = [ring.from(3), ring.from(4), ring.from(5)];
+    /// assert_eq!(ring.sum(&v), ring.from(2));
+    /// assert_eq!(ring.sum(v.iter()), ring.from(2));
+    /// assert_eq!(ring.sum(&[]), ring.from(0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the elements belongs to a different ring.
+    pub fn sum<'a, I>(&'a self, iter: I) -> Modulo<'a>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Modulo<'a>>,
+    {
+        let mut sum = self.from(0u8);
+        for x in iter {
+            sum += x.borrow();
+        }
+        sum
+    }
+}
+
 impl<'a> Neg for Modulo<'a> {
     type Output = Modulo<'a>;
 
@@ -61,17 +61,6 @@ impl ModuloRingSmall {
     }
 }
 
-impl ModuloRingLarge {
-    pub(crate) fn modulus(&self) -> UBig {
-        let normalized_modulus = self.normalized_modulus();
-        let mut buffer = Buffer::allocate(normalized_modulus.len());
-        buffer.extend(normalized_modulus);
-        let low_bits = shift::shr_in_place(&mut buffer, self.shift());
-        assert!(low_bits == 0);
-        buffer.into()
-    }
-}
-
 impl Modulo<'_> {
     /// Get the residue in range `0..n` in an n-element ring.
     ///
@@ -208,17 +197,10 @@ impl<'a> ModuloLarge<'a> {
                 if words.len() < modulus.len() {
                     vec.extend(&*words);
                 } else {
-                    let mut allocation = MemoryAllocation::new(div::memory_requirement_exact(
-                        words.len(),
-                        modulus.len(),
-                    ));
+                    let mut allocation =
+                        MemoryAllocation::new(ring.div_rem_memory_requirement(words.len()));
                     let mut memory = allocation.memory();
-                    let _overflow = div::div_rem_in_place(
-                        &mut words,
-                        modulus,
-                        ring.fast_div_top(),
-                        &mut memory,
-                    );
+                    let _overflow = ring.div_rem_in_place(&mut words, &mut memory);
                     vec.extend(&words[..modulus.len()]);
                 }
             }
@@ -4,12 +4,14 @@
 use crate::{
     arch::word::Word,
     assert::debug_assert_in_const_fn,
-    cmp, div,
+    cmp,
+    divisor::DivisorLarge,
     fast_divide::FastDivideNormalized,
     math,
+    memory::Memory,
     ubig::{Repr, UBig},
 };
-use alloc::vec::Vec;
+use alloc::alloc::Layout;
 use core::cmp::Ordering;
 
 /// A ring of integers modulo a positive integer.
@@ -35,9 +37,7 @@ pub(crate) struct ModuloRingSmall {
 }
 
 pub(crate) struct ModuloRingLarge {
-    normalized_modulus: Vec<Word>,
-    shift: u32,
-    fast_div_top: FastDivideNormalized,
+    modulus: DivisorLarge,
 }
 
 impl ModuloRing {
@@ -108,30 +108,42 @@ impl ModuloRingSmall {
 impl ModuloRingLarge {
     /// Create a new large ring of integers modulo `n`.
     fn new(n: &[Word]) -> ModuloRingLarge {
-        let mut normalized_modulus = n.to_vec();
-        let (shift, fast_div_top) = div::normalize_large(&mut normalized_modulus);
         ModuloRingLarge {
-            normalized_modulus,
-            shift,
-            fast_div_top,
+            modulus: DivisorLarge::new(n),
         }
     }
 
     pub(crate) fn normalized_modulus(&self) -> &[Word] {
-        &self.normalized_modulus
+        self.modulus.normalized_divisor()
     }
 
     pub(crate) fn shift(&self) -> u32 {
-        self.shift
+        self.modulus.shift()
+    }
+
+    pub(crate) fn modulus(&self) -> UBig {
+        self.modulus.divisor()
+    }
+
+    /// Memory requirement for `div_rem_in_place`.
+    pub(crate) fn div_rem_memory_requirement(&self, lhs_len: usize) -> Layout {
+        self.modulus.memory_requirement(lhs_len)
     }
 
-    pub(crate) fn fast_div_top(&self) -> FastDivideNormalized {
-        self.fast_div_top
+    /// Divide lhs by the normalized modulus.
+    ///
+    /// lhs = [lhs / modulus, lhs % modulus]
+    ///
+    /// Returns carry in the quotient.
+    #[must_use]
+    pub(crate) fn div_rem_in_place(&self, lhs: &mut [Word], memory: &mut Memory) -> bool {
+        self.modulus.div_rem_in_place(lhs, memory)
     }
 
     pub(crate) fn is_valid(&self, val: &[Word]) -> bool {
-        val.len() == self.normalized_modulus.len()
-            && cmp::cmp_same_len(val, &self.normalized_modulus) == Ordering::Less
-            && val[0] & math::ones::<Word>(self.shift) == 0
+        let normalized_modulus = self.normalized_modulus();
+        val.len() == normalized_modulus.len()
+            && cmp::cmp_same_len(val, normalized_modulus) == Ordering::Less
+            && val[0] & math::ones::<Word>(self.shift()) == 0
     }
 }
\ No newline at end of file
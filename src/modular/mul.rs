@@ -1,11 +1,10 @@
 use crate::{
     arch::word::Word,
     assert::debug_assert_in_const_fn,
-    div,
     memory::{self, Memory, MemoryAllocation},
     modular::{
         modulo::{Modulo, ModuloLarge, ModuloRepr, ModuloSmall, ModuloSmallRaw},
-        modulo_ring::{ModuloRingLarge, ModuloRingSmall},
+        modulo_ring::{ModuloRing, ModuloRingLarge, ModuloRingSmall},
     },
     mul,
     primitive::extend_word,
@@ -13,7 +12,10 @@ use crate::{
     sign::Sign::Positive,
 };
 use alloc::alloc::Layout;
-use core::ops::{Mul, MulAssign};
+use core::{
+    borrow::Borrow,
+    ops::{Mul, MulAssign},
+};
 
 impl<'a> Mul<Modulo<'a>> for Modulo<'a> {
     type Output = Modulo<'a>;
@@ -79,6 +81,39 @@ impl<'a> MulAssign<&Modulo<'a>> for Modulo<'a> {
     }
 }
 
+impl ModuloRing {
+    /// Product of elements of the ring.
+    ///
+    /// [Modulo] values can't implement [Product](core::iter::Product) because the product of no
+    /// elements would need to know the ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{modular::ModuloRing, ubig};
+    /// let ring = ModuloRing::new(&ubig!(10));
+    /// let v = [ring.from(3), ring.from(4), ring.from(5)];
+    /// assert_eq!(ring.product(&v), ring.from(0));
+    /// assert_eq!(ring.product(&v[..2]), ring.from(2));
+    /// assert_eq!(ring.product(&[]), ring.from(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the elements belongs to a different ring.
+    pub fn product<'a, I>(&'a self, iter: I) -> Modulo<'a>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Modulo<'a>>,
+    {
+        let mut product = self.from(1u8);
+        for x in iter {
+            product *= x.borrow();
+        }
+        product
+    }
+}
+
 impl ModuloSmallRaw {
     #[inline]
     pub(crate) const fn mul(self, other: ModuloSmallRaw, ring: &ModuloRingSmall) -> ModuloSmallRaw {
@@ -107,7 +142,7 @@ impl ModuloRingLarge {
             memory::array_layout::<Word>(2 * n),
             memory::max_layout(
                 mul::memory_requirement_exact(2 * n, n),
-                div::memory_requirement_exact(2 * n, n),
+                self.div_rem_memory_requirement(2 * n),
             ),
         )
     }
@@ -128,7 +163,7 @@ impl ModuloRingLarge {
         assert_eq!(overflow, 0);
         shift::shr_in_place(product, self.shift());
 
-        let _overflow = div::div_rem_in_place(product, modulus, self.fast_div_top(), &mut memory);
+        let _overflow = self.div_rem_in_place(product, &mut memory);
         &product[..n]
     }
 }
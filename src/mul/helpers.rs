@@ -5,7 +5,7 @@ use crate::{
     arch::word::{SignedWord, Word},
     memory::Memory,
     mul,
-    sign::Sign,
+    sign::Sign::{self, *},
 };
 
 /// c += sign * a * b
@@ -47,3 +47,31 @@ where
     }
     carry
 }
+
+/// c += sign * value
+///
+/// `value` may be one word longer than `c`, as long as the extra part is a small
+/// number. It is then returned as part of the carry.
+///
+/// Returns carry.
+#[must_use]
+pub(crate) fn add_signed_in_place_with_excess(
+    c: &mut [Word],
+    sign: Sign,
+    value: &[Word],
+) -> SignedWord {
+    let mut value_len = value.len();
+    while value_len != 0 && value[value_len - 1] == 0 {
+        value_len -= 1;
+    }
+    if value_len <= c.len() {
+        return add::add_signed_in_place(c, sign, &value[..value_len]);
+    }
+    assert!(value_len == c.len() + 1 && value[c.len()] <= 8);
+    let excess = value[c.len()] as SignedWord;
+    let carry = add::add_signed_same_len_in_place(c, sign, &value[..c.len()]);
+    match sign {
+        Positive => carry + excess,
+        Negative => carry - excess,
+    }
+}
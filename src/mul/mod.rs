@@ -3,7 +3,7 @@
 use crate::{
     add,
     arch::word::{SignedWord, Word},
-    memory::Memory,
+    memory::{self, Memory},
     primitive::{double_word, extend_word, split_double_word},
     sign::Sign,
 };
@@ -20,11 +20,19 @@ const_assert!(MAX_LEN_SIMPLE + 1 >= karatsuba::MIN_LEN);
 const MAX_LEN_KARATSUBA: usize = 192;
 const_assert!(MAX_LEN_KARATSUBA + 1 >= toom_3::MIN_LEN);
 
+/// If the larger factor is shorter than this many times the smaller, in quarters,
+/// the factors are considered balanced and Toom-3 can be used.
+///
+/// Otherwise Toom-2.5 or Toom-3.5 is used.
+const MAX_BALANCED_RATIO_QUARTERS: usize = 5;
+
 mod helpers;
 mod karatsuba;
 pub(crate) mod ntt;
 mod simple;
+mod toom_2_5;
 mod toom_3;
+mod toom_3_5;
 
 /// Multiply a word sequence by a `Word` in place.
 ///
@@ -71,7 +79,7 @@ fn add_mul_word_same_len_in_place(words: &mut [Word], mult: Word, rhs: &[Word])
 ///
 /// Returns carry.
 #[must_use]
-fn add_mul_word_in_place(words: &mut [Word], mult: Word, rhs: &[Word]) -> Word {
+pub(crate) fn add_mul_word_in_place(words: &mut [Word], mult: Word, rhs: &[Word]) -> Word {
     assert!(words.len() >= rhs.len());
     let n = rhs.len();
     let mut carry = add_mul_word_same_len_in_place(&mut words[..n], mult, rhs);
@@ -114,13 +122,35 @@ pub(crate) fn memory_requirement_up_to(_total_len: usize, smaller_len: usize) ->
     } else if smaller_len <= MAX_LEN_KARATSUBA {
         karatsuba::memory_requirement_up_to(smaller_len)
     } else {
-        toom_3::memory_requirement_up_to(smaller_len)
+        // The factors may be unbalanced, so any of the Toom variants may be used.
+        memory::max_layout(
+            memory::max_layout(
+                toom_3::memory_requirement_up_to(smaller_len),
+                toom_2_5::memory_requirement_up_to(smaller_len),
+            ),
+            toom_3_5::memory_requirement_up_to(smaller_len),
+        )
     }
 }
 
 /// Temporary scratch space required for multiplication.
 pub(crate) fn memory_requirement_exact(total_len: usize, smaller_len: usize) -> Layout {
-    memory_requirement_up_to(total_len, smaller_len)
+    if smaller_len > MAX_LEN_KARATSUBA && is_balanced(total_len - smaller_len, smaller_len) {
+        // The remainder multiplication in Toom-3 has a factor at most smaller_len / 4 long,
+        // which may be unbalanced.
+        memory::max_layout(
+            toom_3::memory_requirement_up_to(smaller_len),
+            toom_3_5::memory_requirement_up_to(smaller_len / 4),
+        )
+    } else {
+        memory_requirement_up_to(total_len, smaller_len)
+    }
+}
+
+/// Whether the factors are close enough in length for balanced multiplication.
+#[inline]
+fn is_balanced(larger_len: usize, smaller_len: usize) -> bool {
+    4 * larger_len < MAX_BALANCED_RATIO_QUARTERS * smaller_len
 }
 
 /// c += sign * a * b
@@ -144,8 +174,12 @@ pub(crate) fn add_signed_mul<'a>(
         simple::add_signed_mul(c, sign, a, b, memory)
     } else if b.len() <= MAX_LEN_KARATSUBA {
         karatsuba::add_signed_mul(c, sign, a, b, memory)
-    } else {
+    } else if is_balanced(a.len(), b.len()) {
         toom_3::add_signed_mul(c, sign, a, b, memory)
+    } else if a.len() <= 3 * toom_2_5::part_len(b.len()) {
+        toom_2_5::add_signed_mul(c, sign, a, b, memory)
+    } else {
+        toom_3_5::add_signed_mul(c, sign, a, b, memory)
     }
 }
 
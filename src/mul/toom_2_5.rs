@@ -0,0 +1,174 @@
+//! Toom-Cook-2.5 multiplication algorithm for unbalanced factors.
+
+use crate::{
+    add,
+    arch::word::{SignedWord, Word},
+    math,
+    memory::{self, Memory},
+    mul::{self, helpers},
+    shift,
+    sign::Sign::{self, *},
+};
+use alloc::alloc::Layout;
+
+/// Length of the parts that the factors are split into.
+///
+/// The smaller factor is split into 2 parts and the larger into 3 parts.
+#[inline]
+pub(crate) fn part_len(smaller_len: usize) -> usize {
+    (smaller_len + 1) / 2
+}
+
+/// Temporary memory required for multiplication.
+///
+/// n bounds the length of the smaller factor in words.
+pub(crate) fn memory_requirement_up_to(n: usize) -> Layout {
+    // Let m = ceil(n/2), L = ceil log_2 n, so that ceil log_2 m = L - 1.
+    //
+    // While computing V(inf) we use:
+    // product: 2m
+    // plus a general multiplication of length m, which uses at most 8m + 16(L-1) words
+    // (see toom_3_5).
+    // total: 10m + 16L - 16 <= 5n + 16L - 11
+    //
+    // Later we use:
+    // a02:    m + 1
+    // a_eval: m + 1
+    // b_eval: m + 1
+    // v1:     2 * (m + 1)
+    // v_neg1: 2 * (m + 1)
+    // plus a same length multiplication of length m + 1 (see toom_3):
+    // 4(m+1) + 13L
+    // total: 11m + 11 + 13L <= 5.5n + 16.5 + 13L <= 6n + 16L for n >= 33
+    //
+    // So we use 6n + 16 ceil log_2 n.
+    let num_words = 6 * n + 16 * (math::ceil_log_2(n) as usize);
+    memory::array_layout::<Word>(num_words)
+}
+
+/// c += sign * a * b
+/// Toom-Cook-2.5 method: 4 multiplications of size b.len() / 2.
+///
+/// a must be between 2 and 3 times as long as the parts of b.
+///
+/// Returns carry.
+#[must_use]
+pub(crate) fn add_signed_mul(
+    c: &mut [Word],
+    sign: Sign,
+    a: &[Word],
+    b: &[Word],
+    memory: &mut Memory,
+) -> SignedWord {
+    let n = b.len();
+    let m = part_len(n);
+    debug_assert!(n >= 2 && c.len() == a.len() + n);
+    debug_assert!(a.len() > 2 * m && a.len() <= 3 * m);
+
+    // We evaluate the polynomials A(x) = a0 + a1*x + a2*x^2, B(x) = b0 + b1*x
+    // at points 0, 1, -1, infinity.
+    // Multiplying, this gives us values of V(x) = A(x)*B(x) = c0 + c1*x + c2*x^2 + c3*x^3
+    // at the same points (using 4 recursive multiplications).
+    //
+    // Then we interpolate the polynomial coefficients:
+    // c_0 = V(0)
+    // c_1 = t1 - V(inf)
+    // c_2 = t2 - V(0)
+    // c_3 = V(inf)
+    // where:
+    // t1 = (V(1) - V(-1))/2
+    // t2 = (V(1) + V(-1))/2
+
+    // Split into parts. Note: a2, b1 may be shorter.
+    let (a0, a12) = a.split_at(m);
+    let (a1, a2) = a12.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let mut carry: SignedWord = 0;
+
+    // Evaluate at 0.
+    // V(0) = a0 * b0
+    // c_0 += V(0)
+    // c_2 -= V(0)
+    {
+        let (value0, mut memory) = memory.allocate_slice_fill(2 * m, 0);
+        let overflow = mul::add_signed_mul_same_len(value0, Positive, a0, b0, &mut memory);
+        assert!(overflow == 0);
+        carry += add::add_signed_in_place(c, sign, value0);
+        carry += add::add_signed_in_place(&mut c[2 * m..], -sign, value0);
+    }
+
+    // Evaluate at inf.
+    // V(inf) = a2 * b1
+    // c_1 -= V(inf)
+    // c_3 += V(inf)
+    {
+        let (value_inf, mut memory) = memory.allocate_slice_fill(a2.len() + b1.len(), 0);
+        let overflow = mul::add_signed_mul(value_inf, Positive, a2, b1, &mut memory);
+        assert!(overflow == 0);
+        carry += add::add_signed_in_place(&mut c[m..], -sign, value_inf);
+        carry += add::add_signed_in_place(&mut c[3 * m..], sign, value_inf);
+    }
+
+    // a02 = a0 + a2
+    let (a02, mut memory) = memory.allocate_slice_copy_fill(m + 1, a0, 0);
+    a02[m] = Word::from(add::add_in_place(&mut a02[..m], a2));
+    let (a_eval, mut memory) = memory.allocate_slice_fill(m + 1, 0);
+    let (b_eval, mut memory) = memory.allocate_slice_copy_fill(m + 1, b0, 0);
+
+    // Evaluate at 1.
+    // a_eval = a02 + a1
+    // b_eval = b0 + b1
+    // V(1) = a_eval * b_eval
+    let (value1, mut memory) = memory.allocate_slice_fill(2 * (m + 1), 0);
+    {
+        a_eval.copy_from_slice(a02);
+        a_eval[m] += Word::from(add::add_same_len_in_place(&mut a_eval[..m], a1));
+        b_eval[m] = Word::from(add::add_in_place(&mut b_eval[..m], b1));
+        let overflow = mul::add_signed_mul_same_len(value1, Positive, a_eval, b_eval, &mut memory);
+        assert!(overflow == 0);
+    }
+
+    // Evaluate at -1.
+    // a_eval = a02 - a1
+    // b_eval = b0 - b1
+    // V(-1) = a_eval * b_eval
+    let (value_neg1, mut memory) = memory.allocate_slice_fill(2 * (m + 1), 0);
+    let value_neg1_sign = {
+        a_eval.copy_from_slice(a02);
+        let mut value_neg1_sign = add::sub_in_place_with_sign(a_eval, a1);
+        b_eval[..m].copy_from_slice(b0);
+        b_eval[m] = 0;
+        value_neg1_sign *= add::sub_in_place_with_sign(b_eval, b1);
+        let overflow =
+            mul::add_signed_mul_same_len(value_neg1, Positive, a_eval, b_eval, &mut memory);
+        assert!(overflow == 0);
+        value_neg1_sign
+    };
+
+    // t2 = (V(1) + V(-1)) / 2, stored in value1
+    // t1 = t2 - V(-1), stored in value_neg1
+    let overflow = add::add_signed_same_len_in_place(value1, value_neg1_sign, value_neg1);
+    assert!(overflow == 0);
+    let t2_rem = shift::shr_in_place(value1, 1);
+    assert_eq!(t2_rem, 0);
+    let (t2, t1) = (value1, value_neg1);
+    match value_neg1_sign {
+        Positive => {
+            let overflow = add::sub_same_len_in_place_swap(t2, t1);
+            assert!(!overflow);
+        }
+        Negative => {
+            let overflow = add::add_same_len_in_place(t1, t2);
+            assert!(!overflow);
+        }
+    }
+
+    // c_1 += t1
+    // c_2 += t2
+    carry += helpers::add_signed_in_place_with_excess(&mut c[m..], sign, t1);
+    carry += helpers::add_signed_in_place_with_excess(&mut c[2 * m..], sign, t2);
+
+    assert!(carry.abs() <= 1);
+    carry
+}
@@ -0,0 +1,301 @@
+//! Toom-Cook-3.5 multiplication algorithm for unbalanced factors.
+
+use crate::{
+    add,
+    arch::word::{SignedWord, Word},
+    div, math,
+    memory::{self, Memory},
+    mul::{self, helpers},
+    shift,
+    sign::Sign::{self, *},
+};
+use alloc::alloc::Layout;
+
+/// Length of the parts that the factors are split into.
+///
+/// The smaller factor is split into 2 parts and each chunk of the larger factor into 4 parts.
+#[inline]
+fn part_len(smaller_len: usize) -> usize {
+    (smaller_len + 1) / 2
+}
+
+/// Temporary memory required for multiplication.
+///
+/// n bounds the length of the smaller factor in words.
+pub(crate) fn memory_requirement_up_to(n: usize) -> Layout {
+    // We prove by induction that any multiplication with the smaller factor of length n
+    // uses at most f(n) = 8n + 16L words, where m = ceil(n/2), L = ceil log_2 n, so that
+    // ceil log_2 m = L - 1.
+    //
+    // This bounds Toom-3 (4n + 13L) and Toom-2.5 (6n + 16L) as well.
+    //
+    // The evaluations of b are kept for all chunks:
+    // b_eval_1:    m + 1
+    // b_eval_neg1: m + 1
+    // b_eval_2:    m + 1
+    //
+    // In each chunk we use:
+    // t1:     2 * (m + 1)
+    // a_eval: m + 1
+    //
+    // While computing V(inf):
+    // c_eval: 2 * (m + 1)
+    // plus a general multiplication of length m: 8m + 16(L-1)
+    // total: 16m + 16L - 8 <= 8n + 16L
+    //
+    // Later we use:
+    // t2:     2 * (m + 1)
+    // a02:    m + 1
+    // a13:    m + 1
+    // plus a same length multiplication of length m + 1 (see toom_3): 4(m+1) + 13L
+    // total: 14m + 14 + 13L <= 7n + 21 + 13L <= 8n + 16L
+    //
+    // The remainder of the larger factor is multiplied after the evaluations of b
+    // are released, so it also fits in f(n).
+    let num_words = 8 * n + 16 * (math::ceil_log_2(n) as usize);
+    memory::array_layout::<Word>(num_words)
+}
+
+/// c += sign * a * b
+/// Toom-Cook-3.5 method: 5 multiplications of size b.len() / 2 for each chunk of a
+/// of length 2 * b.len().
+///
+/// a must be more than 3 times as long as the parts of b.
+///
+/// Returns carry.
+#[must_use]
+pub(crate) fn add_signed_mul(
+    mut c: &mut [Word],
+    sign: Sign,
+    mut a: &[Word],
+    b: &[Word],
+    memory: &mut Memory,
+) -> SignedWord {
+    let n = b.len();
+    let m = part_len(n);
+    debug_assert!(n >= 2 && c.len() == a.len() + n);
+    debug_assert!(a.len() > 3 * m);
+
+    let mut carry_n = 0; // at c[n]
+    {
+        // The evaluations of B are shared by all chunks.
+        let (b0, b1) = b.split_at(m);
+
+        // b_eval_1 = b0 + b1
+        let (value1, mut memory) = memory.allocate_slice_copy_fill(m + 1, b0, 0);
+        value1[m] = Word::from(add::add_in_place(&mut value1[..m], b1));
+
+        // b_eval_neg1 = b0 - b1
+        let (value_neg1, mut memory) = memory.allocate_slice_copy_fill(m + 1, b0, 0);
+        let value_neg1_sign = add::sub_in_place_with_sign(value_neg1, b1);
+
+        // b_eval_2 = b0 + 2b1
+        let (value2, mut memory) = memory.allocate_slice_copy_fill(m + 1, b0, 0);
+        value2[m] = mul::add_mul_word_in_place(&mut value2[..m], 2, b1);
+
+        let b_eval = EvaluatedB {
+            value1,
+            value_neg1,
+            value_neg1_sign,
+            value2,
+        };
+
+        while a.len() > 3 * m {
+            let chunk_len = a.len().min(4 * m);
+            let (a_lo, a_hi) = a.split_at(chunk_len);
+            // Propagate carry_n
+            carry_n = add::add_signed_word_in_place(&mut c[n..chunk_len + n], carry_n);
+            carry_n += add_signed_mul_chunk(
+                &mut c[..chunk_len + n],
+                sign,
+                a_lo,
+                b,
+                &b_eval,
+                &mut memory,
+            );
+            a = a_hi;
+            c = &mut c[chunk_len..];
+        }
+    }
+    // Propagate carry_n
+    let mut carry = add::add_signed_word_in_place(&mut c[n..], carry_n);
+    if a.len() >= b.len() {
+        carry += mul::add_signed_mul(c, sign, a, b, memory);
+    } else if !a.is_empty() {
+        carry += mul::add_signed_mul(c, sign, b, a, memory);
+    }
+    carry
+}
+
+/// The values of B(x) = b0 + b1*x at points 1, -1, 2.
+struct EvaluatedB<'a> {
+    value1: &'a [Word],
+    value_neg1: &'a [Word],
+    value_neg1_sign: Sign,
+    value2: &'a [Word],
+}
+
+/// c += sign * a * b
+/// Toom-Cook-3.5 method for a single chunk of a: 5 multiplications of size b.len() / 2.
+///
+/// Returns carry.
+#[must_use]
+fn add_signed_mul_chunk(
+    c: &mut [Word],
+    sign: Sign,
+    a: &[Word],
+    b: &[Word],
+    b_eval: &EvaluatedB,
+    memory: &mut Memory,
+) -> SignedWord {
+    let m = part_len(b.len());
+    debug_assert!(c.len() == a.len() + b.len());
+    debug_assert!(a.len() > 3 * m && a.len() <= 4 * m);
+
+    // We evaluate the polynomials A(x) = a0 + a1*x + a2*x^2 + a3*x^3, B(x) = b0 + b1*x
+    // at points 0, 1, -1, 2, infinity.
+    // Multiplying, this gives us values of V(x) = A(x)*B(x) = c0 + c1*x + c2*x^2 + c3*x^3 + c4*x^4
+    // at the same points (using 5 recursive multiplications).
+    //
+    // V(x) has the same degree as in Toom-3, so the interpolation is the same:
+    // c_0 = V(0)
+    // c_1 = V(1) - t1
+    // c_2 = t2 - V(0) - V(inf)
+    // c_3 = t1 - t2
+    // c_4 = V(inf)
+    // where:
+    // t1 = (3V(0) + 2V(-1) + V(2))/6 - 2V(inf)
+    // t2 = (V(1) + V(-1))/2
+
+    // Split into parts. Note: a3, b1 may be shorter.
+    let (a0, a123) = a.split_at(m);
+    let (a1, a23) = a123.split_at(m);
+    let (a2, a3) = a23.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let mut carry: SignedWord = 0;
+
+    // Evaluate at 0.
+    // V(0) = a0 * b0
+    // c_0 += V(0)
+    // c_2 -= V(0)
+    // t1 = 3*V(0)
+    let (t1, mut memory) = memory.allocate_slice_fill(2 * m + 2, 0);
+    {
+        let t1_short = &mut t1[..2 * m];
+        let overflow = mul::add_signed_mul_same_len(t1_short, Positive, a0, b0, &mut memory);
+        assert!(overflow == 0);
+        carry += add::add_signed_in_place(c, sign, t1_short);
+        carry += add::add_signed_in_place(&mut c[2 * m..], -sign, t1_short);
+        t1[2 * m] = mul::mul_word_in_place(t1_short, 3);
+    }
+
+    // Evaluate at 2.
+    // a_eval = a0 + 2a1 + 4a2 + 8a3
+    // V(2) = a_eval * b_eval_2
+    // t1 += V(2)
+    let (a_eval, mut memory) = memory.allocate_slice_copy_fill(m + 1, a0, 0);
+    {
+        a_eval[m] = mul::add_mul_word_same_len_in_place(&mut a_eval[..m], 2, a1);
+        a_eval[m] += mul::add_mul_word_same_len_in_place(&mut a_eval[..m], 4, a2);
+        a_eval[m] += mul::add_mul_word_in_place(&mut a_eval[..m], 8, a3);
+        let overflow =
+            mul::add_signed_mul_same_len(t1, Positive, a_eval, b_eval.value2, &mut memory);
+        assert!(overflow == 0);
+    }
+
+    // Evaluate at inf.
+    // V(inf) = a3 * b1
+    // c_2 -= V(inf)
+    // c_4 += V(inf)
+    // t1 -= 12V(inf)
+    // Now t1 = 3V(0) + V(2) - 12V(inf)
+    {
+        let inf_len = a3.len() + b1.len();
+        let (c_eval, mut memory) = memory.allocate_slice_fill(2 * m + 2, 0);
+        let c_eval_short = &mut c_eval[..inf_len];
+        let overflow = mul::add_signed_mul(c_eval_short, Positive, a3, b1, &mut memory);
+        assert!(overflow == 0);
+        carry += add::add_signed_in_place(&mut c[2 * m..], -sign, c_eval_short);
+        carry += add::add_signed_same_len_in_place(&mut c[4 * m..], sign, c_eval_short);
+        c_eval[inf_len] = mul::mul_word_in_place(c_eval_short, 12);
+        let overflow = add::sub_in_place(t1, &c_eval[..inf_len + 1]);
+        // 3V(0) + V(2) - 12V(inf) is never negative
+        assert!(!overflow);
+    }
+
+    // Sign of V(-1).
+    let mut value_neg1_sign;
+    let (t2, mut memory) = memory.allocate_slice_fill(2 * m + 2, 0);
+    {
+        // Evaluate at 1.
+        // a_eval = a02 + a13
+        // V(1) = a_eval * b_eval_1
+        // c_1 += V(1)
+        // t2 = V(1)
+        // a02 = a0 + a2
+        // a13 = a1 + a3
+        // a02 and a13 take the same amount of space as c_eval.
+        let (a02, mut memory) = memory.allocate_slice_copy_fill(m + 1, a0, 0);
+        a02[m] = Word::from(add::add_same_len_in_place(&mut a02[..m], a2));
+        let (a13, mut memory) = memory.allocate_slice_copy_fill(m + 1, a1, 0);
+        a13[m] = Word::from(add::add_in_place(&mut a13[..m], a3));
+
+        a_eval.copy_from_slice(a02);
+        let overflow = add::add_same_len_in_place(a_eval, a13);
+        assert!(!overflow);
+        let overflow =
+            mul::add_signed_mul_same_len(t2, Positive, a_eval, b_eval.value1, &mut memory);
+        assert!(overflow == 0);
+        carry += add::add_signed_in_place(&mut c[m..], sign, t2);
+
+        // Evaluate at -1.
+        // a_eval = a02 - a13
+        // V(-1) = a_eval * b_eval_neg1
+        // t2 += V(-1)
+        // t1 += 2*V(-1)
+        // Now t1 = 3V(0) + 2V(-1) + V(2) - 12V(inf),
+        //     t2 = V(1) + V(-1).
+        a_eval.copy_from_slice(a02);
+        value_neg1_sign = add::sub_in_place_with_sign(a_eval, a13);
+        value_neg1_sign *= b_eval.value_neg1_sign;
+        // We don't need a02, a13 any more, exit the block so that we can use c_eval again.
+    }
+    let (c_eval, mut memory) = memory.allocate_slice_fill(2 * (m + 1), 0);
+    let overflow =
+        mul::add_signed_mul_same_len(c_eval, Positive, a_eval, b_eval.value_neg1, &mut memory);
+    assert!(overflow == 0);
+    let overflow = add::add_signed_same_len_in_place(t2, value_neg1_sign, c_eval);
+    assert!(overflow == 0);
+    match value_neg1_sign {
+        Positive => {
+            let overflow = mul::add_mul_word_same_len_in_place(t1, 2, c_eval);
+            assert!(overflow == 0);
+        }
+        Negative => {
+            let overflow = mul::sub_mul_word_same_len_in_place(t1, 2, c_eval);
+            assert!(overflow == 0);
+        }
+    }
+
+    // t1 /= 6
+    // t2 /= 2
+    // Now t1 = (3V(0) + 2V(-1) + V(2))/6 - 2V(inf)
+    //     t2 = (V(1) + V(-1))/2
+    let t1_rem = div::div_by_word_in_place(t1, 6);
+    let t2_rem = shift::shr_in_place(t2, 1);
+    assert_eq!(t1_rem, 0);
+    assert_eq!(t2_rem, 0);
+
+    // c1 -= t1
+    // c3 += t1
+    // c2 += t2
+    // c3 -= t2
+    carry += helpers::add_signed_in_place_with_excess(&mut c[m..], -sign, t1);
+    carry += helpers::add_signed_in_place_with_excess(&mut c[3 * m..], sign, t1);
+    carry += helpers::add_signed_in_place_with_excess(&mut c[2 * m..], sign, t2);
+    carry += helpers::add_signed_in_place_with_excess(&mut c[3 * m..], -sign, t2);
+
+    assert!(carry.abs() <= 1);
+    carry
+}
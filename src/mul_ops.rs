@@ -2,11 +2,12 @@
 //! Multiplication operators.
 
 use crate::{
+    add,
     arch::word::Word,
     buffer::Buffer,
     helper_macros,
     ibig::IBig,
-    memory::MemoryAllocation,
+    memory::{MemoryAllocation, Scratch},
     mul,
     primitive::{extend_word, PrimitiveSigned, PrimitiveUnsigned},
     sign::Sign::{self, *},
@@ -300,6 +301,215 @@ impl_mul_ibig_primitive!(i128);
 impl_mul_ibig_primitive!(isize);
 
 impl UBig {
+    /// Multiply `a` by `b`, storing the result in `out`.
+    ///
+    /// Temporary memory comes from `scratch`, and the allocation of `out` is reused when it is
+    /// large enough, so that repeated multiplications of similar size don't allocate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, Scratch, UBig};
+    /// let mut scratch = Scratch::new();
+    /// let mut out = UBig::default();
+    /// UBig::mul_into(&mut out, &ubig!(1000), &ubig!(30), &mut scratch);
+    /// assert_eq!(out, ubig!(30000));
+    /// ```
+    pub fn mul_into(out: &mut UBig, a: &UBig, b: &UBig, scratch: &mut Scratch) {
+        let (lhs, rhs) = (a.as_words(), b.as_words());
+        let (lhs, rhs) = if lhs.len() >= rhs.len() {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+        if rhs.is_empty() || rhs == [0] {
+            *out = UBig::from_word(0);
+            return;
+        }
+        if lhs.len() == 1 {
+            *out = UBig::mul_word(lhs[0], rhs[0]);
+            return;
+        }
+
+        // This may be 1 too large.
+        const_assert!(Buffer::MAX_CAPACITY - UBig::MAX_LEN >= 1);
+        let res_len = lhs.len() + rhs.len();
+        let mut buffer = match mem::take(out).into_repr() {
+            Small(_) => Buffer::allocate(res_len),
+            Large(mut buffer) => {
+                buffer.truncate(0);
+                buffer.ensure_capacity(res_len);
+                buffer
+            }
+        };
+        if rhs.len() == 1 {
+            buffer.extend(lhs);
+            let carry = mul::mul_word_in_place(&mut buffer, rhs[0]);
+            buffer.push(carry);
+        } else {
+            buffer.push_zeros(res_len);
+            let mut memory = scratch.memory(mul::memory_requirement_exact(res_len, rhs.len()));
+            let overflow = mul::add_signed_mul(&mut buffer, Positive, lhs, rhs, &mut memory);
+            assert!(overflow == 0);
+        }
+        *out = buffer.into();
+    }
+
+    /// Add `a * b` to `self`.
+    ///
+    /// The product is accumulated directly into `self`, without allocating a temporary for it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut x = ubig!(5);
+    /// x.add_mul(&ubig!(1000), &ubig!(30));
+    /// assert_eq!(x, ubig!(30005));
+    /// ```
+    #[inline]
+    pub fn add_mul(&mut self, a: &UBig, b: &UBig) {
+        self.add_mul_with_scratch(a, b, &mut Scratch::new())
+    }
+
+    /// Add `a * b` to `self`, using `scratch` for temporary memory.
+    ///
+    /// See [UBig::mul_into] for how `scratch` is reused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, Scratch};
+    /// let mut scratch = Scratch::new();
+    /// let mut x = ubig!(5);
+    /// x.add_mul_with_scratch(&ubig!(1000), &ubig!(30), &mut scratch);
+    /// assert_eq!(x, ubig!(30005));
+    /// ```
+    #[inline]
+    pub fn add_mul_with_scratch(&mut self, a: &UBig, b: &UBig, scratch: &mut Scratch) {
+        let sign = self.add_signed_mul(Positive, a.as_words(), b.as_words(), scratch);
+        debug_assert!(sign == Positive);
+    }
+
+    /// Subtract `a * b` from `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut x = ubig!(30005);
+    /// x.sub_mul(&ubig!(1000), &ubig!(30));
+    /// assert_eq!(x, ubig!(5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be negative.
+    #[inline]
+    pub fn sub_mul(&mut self, a: &UBig, b: &UBig) {
+        self.sub_mul_with_scratch(a, b, &mut Scratch::new())
+    }
+
+    /// Subtract `a * b` from `self`, using `scratch` for temporary memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, Scratch};
+    /// let mut scratch = Scratch::new();
+    /// let mut x = ubig!(30005);
+    /// x.sub_mul_with_scratch(&ubig!(1000), &ubig!(30), &mut scratch);
+    /// assert_eq!(x, ubig!(5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be negative.
+    #[inline]
+    pub fn sub_mul_with_scratch(&mut self, a: &UBig, b: &UBig, scratch: &mut Scratch) {
+        if self.add_signed_mul(Negative, a.as_words(), b.as_words(), scratch) == Negative {
+            UBig::panic_negative()
+        }
+    }
+
+    /// Add `a * b` to `self`, where `b` is a [Word].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut x = ubig!(5);
+    /// x.add_mul_word(&ubig!(1000), 30);
+    /// assert_eq!(x, ubig!(30005));
+    /// ```
+    pub fn add_mul_word(&mut self, a: &UBig, b: Word) {
+        let a = a.as_words();
+        if a.is_empty() || b == 0 {
+            return;
+        }
+        // One extra word for the carry.
+        let len = self.as_words().len().max(a.len()) + 1;
+        let mut buffer = mem::take(self).into_buffer_for(len);
+        buffer.push_zeros(len - buffer.len());
+        let carry = mul::add_mul_word_in_place(&mut buffer, b, a);
+        debug_assert!(carry == 0);
+        *self = buffer.into();
+    }
+
+    /// Set `self` to `self * a + b`.
+    ///
+    /// The product is accumulated directly onto `b`, reusing its allocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// let mut x = ubig!(1000);
+    /// x.mul_add_assign(&ubig!(30), ubig!(5));
+    /// assert_eq!(x, ubig!(30005));
+    /// ```
+    pub fn mul_add_assign(&mut self, a: &UBig, b: UBig) {
+        let (lhs, rhs) = (self.as_words(), a.as_words());
+        let mut buffer = b.into_buffer_for(lhs.len() + rhs.len() + 1);
+        let sign = add_signed_mul_in_place(&mut buffer, Positive, lhs, rhs, &mut Scratch::new());
+        debug_assert!(sign == Positive);
+        *self = buffer.into();
+    }
+
+    /// self += sign * a * b
+    ///
+    /// Returns the sign of the result, `self` is set to its magnitude.
+    fn add_signed_mul(
+        &mut self,
+        sign: Sign,
+        a: &[Word],
+        b: &[Word],
+        scratch: &mut Scratch,
+    ) -> Sign {
+        if a.is_empty() || b.is_empty() {
+            return Positive;
+        }
+        let mut buffer = mem::take(self).into_buffer_for(a.len() + b.len() + 1);
+        let sign = add_signed_mul_in_place(&mut buffer, sign, a, b, scratch);
+        *self = buffer.into();
+        sign
+    }
+
+    /// Convert into a `Buffer` with capacity for at least `num_words`.
+    fn into_buffer_for(self, num_words: usize) -> Buffer {
+        match self.into_repr() {
+            Small(word) => {
+                let mut buffer = Buffer::allocate(num_words);
+                buffer.push(word);
+                buffer
+            }
+            Large(mut buffer) => {
+                buffer.ensure_capacity(num_words);
+                buffer
+            }
+        }
+    }
+
     /// Multiply two `Word`s.
     #[inline]
     fn mul_word(a: Word, b: Word) -> UBig {
@@ -373,6 +583,146 @@ impl UBig {
 }
 
 impl IBig {
+    /// Multiply `a` by `b`, storing the result in `out`.
+    ///
+    /// See [UBig::mul_into].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig, Scratch};
+    /// let mut scratch = Scratch::new();
+    /// let mut out = IBig::default();
+    /// IBig::mul_into(&mut out, &ibig!(-1000), &ibig!(30), &mut scratch);
+    /// assert_eq!(out, ibig!(-30000));
+    /// ```
+    #[inline]
+    pub fn mul_into(out: &mut IBig, a: &IBig, b: &IBig, scratch: &mut Scratch) {
+        let (_, mut magnitude) = mem::take(out).into_sign_magnitude();
+        UBig::mul_into(&mut magnitude, a.magnitude(), b.magnitude(), scratch);
+        *out = IBig::from_sign_magnitude(a.sign() * b.sign(), magnitude);
+    }
+
+    /// Add `a * b` to `self`.
+    ///
+    /// See [UBig::add_mul].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// let mut x = ibig!(5);
+    /// x.add_mul(&ibig!(-1000), &ibig!(30));
+    /// assert_eq!(x, ibig!(-29995));
+    /// ```
+    #[inline]
+    pub fn add_mul(&mut self, a: &IBig, b: &IBig) {
+        self.add_mul_with_scratch(a, b, &mut Scratch::new())
+    }
+
+    /// Add `a * b` to `self`, using `scratch` for temporary memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, Scratch};
+    /// let mut scratch = Scratch::new();
+    /// let mut x = ibig!(5);
+    /// x.add_mul_with_scratch(&ibig!(-1000), &ibig!(30), &mut scratch);
+    /// assert_eq!(x, ibig!(-29995));
+    /// ```
+    #[inline]
+    pub fn add_mul_with_scratch(&mut self, a: &IBig, b: &IBig, scratch: &mut Scratch) {
+        self.add_signed_mul(a.sign() * b.sign(), a.magnitude(), b.magnitude(), scratch);
+    }
+
+    /// Subtract `a * b` from `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// let mut x = ibig!(5);
+    /// x.sub_mul(&ibig!(1000), &ibig!(30));
+    /// assert_eq!(x, ibig!(-29995));
+    /// ```
+    #[inline]
+    pub fn sub_mul(&mut self, a: &IBig, b: &IBig) {
+        self.sub_mul_with_scratch(a, b, &mut Scratch::new())
+    }
+
+    /// Subtract `a * b` from `self`, using `scratch` for temporary memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, Scratch};
+    /// let mut scratch = Scratch::new();
+    /// let mut x = ibig!(5);
+    /// x.sub_mul_with_scratch(&ibig!(1000), &ibig!(30), &mut scratch);
+    /// assert_eq!(x, ibig!(-29995));
+    /// ```
+    #[inline]
+    pub fn sub_mul_with_scratch(&mut self, a: &IBig, b: &IBig, scratch: &mut Scratch) {
+        self.add_signed_mul(
+            -(a.sign() * b.sign()),
+            a.magnitude(),
+            b.magnitude(),
+            scratch,
+        );
+    }
+
+    /// Add `a * b` to `self`, where `b` is a [Word].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// let mut x = ibig!(5);
+    /// x.add_mul_word(&ibig!(-1000), 30);
+    /// assert_eq!(x, ibig!(-29995));
+    /// ```
+    #[inline]
+    pub fn add_mul_word(&mut self, a: &IBig, b: Word) {
+        self.add_signed_mul(
+            a.sign(),
+            a.magnitude(),
+            &UBig::from_word(b),
+            &mut Scratch::new(),
+        );
+    }
+
+    /// Set `self` to `self * a + b`.
+    ///
+    /// See [UBig::mul_add_assign].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// let mut x = ibig!(1000);
+    /// x.mul_add_assign(&ibig!(-30), ibig!(5));
+    /// assert_eq!(x, ibig!(-29995));
+    /// ```
+    pub fn mul_add_assign(&mut self, a: &IBig, b: IBig) {
+        let (lhs, rhs) = (self.magnitude().as_words(), a.magnitude().as_words());
+        let (b_sign, b_magnitude) = b.into_sign_magnitude();
+        let mut buffer = b_magnitude.into_buffer_for(lhs.len() + rhs.len() + 1);
+        // b + self * a = b.sign * (|b| + b.sign * sign * |self * a|)
+        let sign = self.sign() * a.sign() * b_sign;
+        let sign =
+            b_sign * add_signed_mul_in_place(&mut buffer, sign, lhs, rhs, &mut Scratch::new());
+        *self = IBig::from_sign_magnitude(sign, buffer.into());
+    }
+
+    /// self += sign * a * b
+    fn add_signed_mul(&mut self, sign: Sign, a: &UBig, b: &UBig, scratch: &mut Scratch) {
+        let (self_sign, mut magnitude) = mem::take(self).into_sign_magnitude();
+        let sign = self_sign
+            * magnitude.add_signed_mul(self_sign * sign, a.as_words(), b.as_words(), scratch);
+        *self = IBig::from_sign_magnitude(sign, magnitude);
+    }
+
     #[inline]
     fn mul_primitive<T>(self, rhs: T) -> IBig
     where
@@ -396,4 +746,38 @@ impl IBig {
     {
         *self *= IBig::from(rhs)
     }
+}
+
+/// buffer += sign * a * b, where `buffer` holds a magnitude.
+///
+/// Returns the sign of the result, `buffer` is set to its magnitude.
+fn add_signed_mul_in_place(
+    buffer: &mut Buffer,
+    sign: Sign,
+    a: &[Word],
+    b: &[Word],
+    scratch: &mut Scratch,
+) -> Sign {
+    if a.is_empty() || b.is_empty() {
+        return Positive;
+    }
+    let res_len = a.len() + b.len();
+    // One extra word for the carry.
+    let len = buffer.len().max(res_len) + 1;
+    buffer.ensure_capacity(len);
+    buffer.push_zeros(len - buffer.len());
+
+    let mut memory = scratch.memory(mul::memory_requirement_exact(res_len, a.len().min(b.len())));
+    let carry = mul::add_signed_mul(&mut buffer[..res_len], sign, a, b, &mut memory);
+    if add::add_signed_word_in_place(&mut buffer[res_len..], carry) == 0 {
+        Positive
+    } else {
+        // The result is negative, in two's complement.
+        for word in buffer.iter_mut() {
+            *word = !*word;
+        }
+        let overflow = add::add_one_in_place(buffer);
+        debug_assert!(!overflow);
+        Negative
+    }
 }
\ No newline at end of file
@@ -0,0 +1,167 @@
+//! Implement num-integer traits.
+
+use crate::{ibig::IBig, ops::DivRem, ubig::UBig};
+use num_integer::ExtendedGcd;
+use num_traits::Zero;
+
+impl num_integer::Integer for UBig {
+    #[inline]
+    fn div_floor(&self, other: &UBig) -> UBig {
+        self / other
+    }
+
+    #[inline]
+    fn mod_floor(&self, other: &UBig) -> UBig {
+        self % other
+    }
+
+    #[inline]
+    fn div_mod_floor(&self, other: &UBig) -> (UBig, UBig) {
+        DivRem::div_rem(self, other)
+    }
+
+    /// The greatest common divisor of 0 and 0 is 0.
+    #[inline]
+    fn gcd(&self, other: &UBig) -> UBig {
+        if self.is_zero() && other.is_zero() {
+            UBig::from(0u8)
+        } else {
+            self.gcd(other)
+        }
+    }
+
+    #[inline]
+    fn lcm(&self, other: &UBig) -> UBig {
+        self.lcm(other)
+    }
+
+    fn gcd_lcm(&self, other: &UBig) -> (UBig, UBig) {
+        if self.is_zero() || other.is_zero() {
+            return (num_integer::Integer::gcd(self, other), UBig::from(0u8));
+        }
+        let g = self.gcd(other);
+        let l = self / &g * other;
+        (g, l)
+    }
+
+    #[inline]
+    fn is_multiple_of(&self, other: &UBig) -> bool {
+        if other.is_zero() {
+            self.is_zero()
+        } else {
+            (self % other).is_zero()
+        }
+    }
+
+    #[inline]
+    fn is_even(&self) -> bool {
+        !self.bit(0)
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.bit(0)
+    }
+
+    #[inline]
+    fn div_rem(&self, other: &UBig) -> (UBig, UBig) {
+        DivRem::div_rem(self, other)
+    }
+}
+
+impl num_integer::Integer for IBig {
+    #[inline]
+    fn div_floor(&self, other: &IBig) -> IBig {
+        self.div_mod_floor(other).0
+    }
+
+    #[inline]
+    fn mod_floor(&self, other: &IBig) -> IBig {
+        self.div_mod_floor(other).1
+    }
+
+    fn div_mod_floor(&self, other: &IBig) -> (IBig, IBig) {
+        let (q, r) = DivRem::div_rem(self, other);
+        if !r.is_zero() && r.sign() != other.sign() {
+            (q - IBig::from(1u8), r + other)
+        } else {
+            (q, r)
+        }
+    }
+
+    /// The greatest common divisor of 0 and 0 is 0.
+    #[inline]
+    fn gcd(&self, other: &IBig) -> IBig {
+        IBig::from(num_integer::Integer::gcd(
+            self.magnitude(),
+            other.magnitude(),
+        ))
+    }
+
+    #[inline]
+    fn lcm(&self, other: &IBig) -> IBig {
+        self.lcm(other)
+    }
+
+    #[inline]
+    fn gcd_lcm(&self, other: &IBig) -> (IBig, IBig) {
+        let (g, l) = num_integer::Integer::gcd_lcm(self.magnitude(), other.magnitude());
+        (IBig::from(g), IBig::from(l))
+    }
+
+    fn extended_gcd(&self, other: &IBig) -> ExtendedGcd<IBig> {
+        if self.is_zero() && other.is_zero() {
+            return ExtendedGcd {
+                gcd: IBig::from(0u8),
+                x: IBig::from(1u8),
+                y: IBig::from(0u8),
+            };
+        }
+        let (gcd, x, y) = self.extended_gcd(other);
+        ExtendedGcd { gcd, x, y }
+    }
+
+    #[inline]
+    fn is_multiple_of(&self, other: &IBig) -> bool {
+        num_integer::Integer::is_multiple_of(self.magnitude(), other.magnitude())
+    }
+
+    #[inline]
+    fn is_even(&self) -> bool {
+        !self.magnitude().bit(0)
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.magnitude().bit(0)
+    }
+
+    #[inline]
+    fn div_rem(&self, other: &IBig) -> (IBig, IBig) {
+        DivRem::div_rem(self, other)
+    }
+}
+
+impl num_integer::Roots for UBig {
+    #[inline]
+    fn nth_root(&self, n: u32) -> UBig {
+        self.nth_root(n as usize)
+    }
+
+    #[inline]
+    fn sqrt(&self) -> UBig {
+        self.sqrt()
+    }
+}
+
+impl num_integer::Roots for IBig {
+    #[inline]
+    fn nth_root(&self, n: u32) -> IBig {
+        self.nth_root(n as usize)
+    }
+
+    #[inline]
+    fn sqrt(&self) -> IBig {
+        self.sqrt()
+    }
+}
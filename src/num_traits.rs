@@ -1,7 +1,16 @@
 
 //! Implement num-traits traits.
 
-use crate::{error::ParseError, ibig::IBig, ops::Abs, ubig::UBig};
+use crate::{
+    error::ParseError,
+    ibig::IBig,
+    modular::Modulo,
+    ops::{Abs, DivEuclid, RemEuclid},
+    sign::Sign::*,
+    ubig::UBig,
+};
+use core::convert::TryInto;
+use num_traits::Zero;
 
 impl num_traits::Zero for UBig {
     #[inline]
@@ -120,4 +129,389 @@ impl num_traits::Num for IBig {
     fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
         Self::from_str_radix(s, radix)
     }
+}
+
+impl num_traits::ToPrimitive for UBig {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.try_into().ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.try_into().ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.try_into().ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.try_into().ok()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.to_f32())
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.to_f64())
+    }
+}
+
+impl num_traits::ToPrimitive for IBig {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.try_into().ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.try_into().ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.try_into().ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.try_into().ok()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.to_f32())
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.to_f64())
+    }
+}
+
+impl num_traits::FromPrimitive for UBig {
+    #[inline]
+    fn from_i64(n: i64) -> Option<UBig> {
+        n.try_into().ok()
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<UBig> {
+        n.try_into().ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<UBig> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<UBig> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<UBig> {
+        ibig_from_f64(n)?.try_into().ok()
+    }
+}
+
+impl num_traits::FromPrimitive for IBig {
+    #[inline]
+    fn from_i64(n: i64) -> Option<IBig> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<IBig> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<IBig> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<IBig> {
+        Some(n.into())
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<IBig> {
+        ibig_from_f64(n)
+    }
+}
+
+/// Convert a float to an integer, rounding towards zero.
+///
+/// Returns `None` for infinities and NaN.
+fn ibig_from_f64(n: f64) -> Option<IBig> {
+    if !n.is_finite() {
+        return None;
+    }
+    let bits = n.to_bits();
+    let sign = if n.is_sign_negative() {
+        Negative
+    } else {
+        Positive
+    };
+    let exponent = (bits >> 52 & 0x7ff) as usize;
+    if exponent < 1023 {
+        // |n| < 1
+        return Some(IBig::from(0u8));
+    }
+    // |n| = mantissa * 2^(exponent - 1075)
+    let mantissa = UBig::from(bits & ((1 << 52) - 1) | 1 << 52);
+    let magnitude = if exponent >= 1075 {
+        mantissa << (exponent - 1075)
+    } else {
+        mantissa >> (1075 - exponent)
+    };
+    Some(IBig::from_sign_magnitude(sign, magnitude))
+}
+
+impl num_traits::NumCast for UBig {
+    #[inline]
+    fn from<T: num_traits::ToPrimitive>(n: T) -> Option<UBig> {
+        match n.to_u128() {
+            Some(n) => Some(n.into()),
+            None => num_traits::FromPrimitive::from_f64(n.to_f64()?),
+        }
+    }
+}
+
+impl num_traits::NumCast for IBig {
+    #[inline]
+    fn from<T: num_traits::ToPrimitive>(n: T) -> Option<IBig> {
+        if let Some(n) = n.to_i128() {
+            Some(n.into())
+        } else if let Some(n) = n.to_u128() {
+            Some(n.into())
+        } else {
+            ibig_from_f64(n.to_f64()?)
+        }
+    }
+}
+
+impl num_traits::CheckedAdd for UBig {
+    #[inline]
+    fn checked_add(&self, v: &UBig) -> Option<UBig> {
+        Some(self + v)
+    }
+}
+
+impl num_traits::CheckedAdd for IBig {
+    #[inline]
+    fn checked_add(&self, v: &IBig) -> Option<IBig> {
+        Some(self + v)
+    }
+}
+
+impl num_traits::CheckedSub for UBig {
+    #[inline]
+    fn checked_sub(&self, v: &UBig) -> Option<UBig> {
+        if self >= v {
+            Some(self - v)
+        } else {
+            None
+        }
+    }
+}
+
+impl num_traits::CheckedSub for IBig {
+    #[inline]
+    fn checked_sub(&self, v: &IBig) -> Option<IBig> {
+        Some(self - v)
+    }
+}
+
+impl num_traits::CheckedMul for UBig {
+    #[inline]
+    fn checked_mul(&self, v: &UBig) -> Option<UBig> {
+        Some(self * v)
+    }
+}
+
+impl num_traits::CheckedMul for IBig {
+    #[inline]
+    fn checked_mul(&self, v: &IBig) -> Option<IBig> {
+        Some(self * v)
+    }
+}
+
+impl num_traits::CheckedDiv for UBig {
+    #[inline]
+    fn checked_div(&self, v: &UBig) -> Option<UBig> {
+        if v.is_zero() {
+            None
+        } else {
+            Some(self / v)
+        }
+    }
+}
+
+impl num_traits::CheckedDiv for IBig {
+    #[inline]
+    fn checked_div(&self, v: &IBig) -> Option<IBig> {
+        if v.is_zero() {
+            None
+        } else {
+            Some(self / v)
+        }
+    }
+}
+
+impl num_traits::CheckedRem for UBig {
+    #[inline]
+    fn checked_rem(&self, v: &UBig) -> Option<UBig> {
+        if v.is_zero() {
+            None
+        } else {
+            Some(self % v)
+        }
+    }
+}
+
+impl num_traits::CheckedRem for IBig {
+    #[inline]
+    fn checked_rem(&self, v: &IBig) -> Option<IBig> {
+        if v.is_zero() {
+            None
+        } else {
+            Some(self % v)
+        }
+    }
+}
+
+impl num_traits::CheckedNeg for UBig {
+    #[inline]
+    fn checked_neg(&self) -> Option<UBig> {
+        if self.is_zero() {
+            Some(UBig::from(0u8))
+        } else {
+            None
+        }
+    }
+}
+
+impl num_traits::CheckedNeg for IBig {
+    #[inline]
+    fn checked_neg(&self) -> Option<IBig> {
+        Some(-self)
+    }
+}
+
+impl num_traits::Euclid for UBig {
+    #[inline]
+    fn div_euclid(&self, v: &UBig) -> UBig {
+        self / v
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &UBig) -> UBig {
+        self % v
+    }
+}
+
+impl num_traits::Euclid for IBig {
+    #[inline]
+    fn div_euclid(&self, v: &IBig) -> IBig {
+        DivEuclid::div_euclid(self, v)
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &IBig) -> IBig {
+        RemEuclid::rem_euclid(self, v)
+    }
+}
+
+impl num_traits::CheckedEuclid for UBig {
+    #[inline]
+    fn checked_div_euclid(&self, v: &UBig) -> Option<UBig> {
+        num_traits::CheckedDiv::checked_div(self, v)
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &UBig) -> Option<UBig> {
+        num_traits::CheckedRem::checked_rem(self, v)
+    }
+}
+
+impl num_traits::CheckedEuclid for IBig {
+    #[inline]
+    fn checked_div_euclid(&self, v: &IBig) -> Option<IBig> {
+        if v.is_zero() {
+            None
+        } else {
+            Some(DivEuclid::div_euclid(self, v))
+        }
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &IBig) -> Option<IBig> {
+        if v.is_zero() {
+            None
+        } else {
+            Some(RemEuclid::rem_euclid(self, v))
+        }
+    }
+}
+
+impl num_traits::MulAdd for UBig {
+    type Output = UBig;
+
+    #[inline]
+    fn mul_add(mut self, a: UBig, b: UBig) -> UBig {
+        UBig::mul_add_assign(&mut self, &a, b);
+        self
+    }
+}
+
+impl num_traits::MulAdd for IBig {
+    type Output = IBig;
+
+    #[inline]
+    fn mul_add(mut self, a: IBig, b: IBig) -> IBig {
+        IBig::mul_add_assign(&mut self, &a, b);
+        self
+    }
+}
+
+impl num_traits::MulAddAssign for UBig {
+    #[inline]
+    fn mul_add_assign(&mut self, a: UBig, b: UBig) {
+        UBig::mul_add_assign(self, &a, b)
+    }
+}
+
+impl num_traits::MulAddAssign for IBig {
+    #[inline]
+    fn mul_add_assign(&mut self, a: IBig, b: IBig) {
+        IBig::mul_add_assign(self, &a, b)
+    }
+}
+
+impl<'a> num_traits::Inv for Modulo<'a> {
+    type Output = Option<Modulo<'a>>;
+
+    #[inline]
+    fn inv(self) -> Option<Modulo<'a>> {
+        self.inverse()
+    }
+}
+
+impl<'a> num_traits::Inv for &Modulo<'a> {
+    type Output = Option<Modulo<'a>>;
+
+    #[inline]
+    fn inv(self) -> Option<Modulo<'a>> {
+        self.inverse()
+    }
 }
\ No newline at end of file
@@ -2,14 +2,27 @@
 
 use crate::{
     arch::word::Word,
+    bits::set_ones,
     buffer::Buffer,
     ibig::IBig,
+    math,
+    modular::{
+        modulo::{ModuloLarge, ModuloSmall, ModuloSmallRaw},
+        modulo_ring::ModuloRingRepr,
+        Modulo, ModuloRing,
+    },
     ops::UnsignedAbs,
+    primitive::WORD_BITS_USIZE,
+    sign::Sign::*,
     ubig::{Repr::*, UBig},
 };
+use alloc::vec;
 
 use rand::{
-    distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler},
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformSampler},
+        Distribution,
+    },
     Rng,
 };
 
@@ -196,3 +209,286 @@ impl UniformSampler for UniformIBig {
         IBig::from(UBig::uniform(&self.range, rng)) + &self.offset
     }
 }
+
+/// Random [UBig] with a given number of bits.
+///
+/// By default the result is uniform in `[0, 2^bits)`. Optionally the top bit can be forced, so
+/// that the result has exactly `bits` bits, and the result can be forced to be odd.
+///
+/// # Example
+///
+/// ```
+/// use ibig::rand::RandomBits;
+/// use rand::{thread_rng, Rng};
+/// let x = thread_rng().sample(RandomBits::new(100).exact_top_bit(true).odd(true));
+/// assert_eq!(x.bit_len(), 100);
+/// assert!(x.bit(0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RandomBits {
+    bits: usize,
+    exact_top_bit: bool,
+    odd: bool,
+}
+
+impl RandomBits {
+    /// Uniform distribution in `[0, 2^bits)`.
+    #[inline]
+    pub fn new(bits: usize) -> RandomBits {
+        if bits > UBig::MAX_BIT_LEN {
+            UBig::panic_number_too_large();
+        }
+        RandomBits {
+            bits,
+            exact_top_bit: false,
+            odd: false,
+        }
+    }
+
+    /// Force bit `bits - 1` to be set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is 0.
+    #[inline]
+    pub fn exact_top_bit(self, exact_top_bit: bool) -> RandomBits {
+        assert!(
+            !exact_top_bit || self.bits != 0,
+            "exact top bit with 0 bits"
+        );
+        RandomBits {
+            exact_top_bit,
+            ..self
+        }
+    }
+
+    /// Force bit 0 to be set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is 0.
+    #[inline]
+    pub fn odd(self, odd: bool) -> RandomBits {
+        assert!(!odd || self.bits != 0, "odd number with 0 bits");
+        RandomBits { odd, ..self }
+    }
+}
+
+impl Distribution<UBig> for RandomBits {
+    fn sample<R>(&self, rng: &mut R) -> UBig
+    where
+        R: Rng + ?Sized,
+    {
+        let mut words = random_words(self.bits, rng);
+        if self.exact_top_bit {
+            let i = self.bits - 1;
+            words[i / WORD_BITS_USIZE] |= 1 << (i % WORD_BITS_USIZE);
+        }
+        if self.odd {
+            words[0] |= 1;
+        }
+        words.into()
+    }
+}
+
+/// Uniformly random words for `bits` bits, with higher bits cleared.
+fn random_words<R>(bits: usize, rng: &mut R) -> Buffer
+where
+    R: Rng + ?Sized,
+{
+    let len = math::ceil_div_usize(bits, WORD_BITS_USIZE);
+    let mut words = Buffer::allocate(len);
+    words.push_zeros(len);
+    rng.fill(&mut words[..]);
+    let top_bits = bits % WORD_BITS_USIZE;
+    if top_bits != 0 {
+        words[len - 1] &= math::ones_word(top_bits as u32);
+    }
+    words
+}
+
+/// Random [IBig] concentrated near zero.
+///
+/// The bit length of the magnitude is uniform in `[0, max_bits]`, the magnitude is uniform
+/// among numbers of that bit length, and the sign is uniform. Small values, and values of every
+/// scale up to `max_bits` bits, are all likely.
+///
+/// # Example
+///
+/// ```
+/// use ibig::{ops::UnsignedAbs, rand::RandomIBig};
+/// use rand::{thread_rng, Rng};
+/// let x = thread_rng().sample(RandomIBig::new(100));
+/// assert!(x.unsigned_abs().bit_len() <= 100);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RandomIBig {
+    max_bits: usize,
+}
+
+impl RandomIBig {
+    /// Distribution with magnitudes of at most `max_bits` bits.
+    #[inline]
+    pub fn new(max_bits: usize) -> RandomIBig {
+        if max_bits > UBig::MAX_BIT_LEN {
+            UBig::panic_number_too_large();
+        }
+        RandomIBig { max_bits }
+    }
+}
+
+impl Distribution<IBig> for RandomIBig {
+    fn sample<R>(&self, rng: &mut R) -> IBig
+    where
+        R: Rng + ?Sized,
+    {
+        let bits = rng.gen_range(0..=self.max_bits);
+        let magnitude = if bits == 0 {
+            UBig::from_word(0)
+        } else {
+            rng.sample(RandomBits::new(bits).exact_top_bit(true))
+        };
+        let sign = if rng.gen() { Negative } else { Positive };
+        IBig::from_sign_magnitude(sign, magnitude)
+    }
+}
+
+/// Random [UBig] with long runs of 0s and 1s, with exactly a given number of bits.
+///
+/// Such numbers are rare among uniformly random numbers but exercise carry and borrow
+/// propagation, similar to GMP's `mpz_rrandomb`. Run lengths vary from single bits up to
+/// the whole number.
+///
+/// # Example
+///
+/// ```
+/// use ibig::rand::RandomBitRuns;
+/// use rand::{thread_rng, Rng};
+/// let x = thread_rng().sample(RandomBitRuns::new(1000));
+/// assert_eq!(x.bit_len(), 1000);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RandomBitRuns {
+    bits: usize,
+}
+
+impl RandomBitRuns {
+    /// Distribution in `[2^(bits-1), 2^bits)`, or 0 if `bits` is 0.
+    #[inline]
+    pub fn new(bits: usize) -> RandomBitRuns {
+        if bits > UBig::MAX_BIT_LEN {
+            UBig::panic_number_too_large();
+        }
+        RandomBitRuns { bits }
+    }
+}
+
+impl Distribution<UBig> for RandomBitRuns {
+    fn sample<R>(&self, rng: &mut R) -> UBig
+    where
+        R: Rng + ?Sized,
+    {
+        let len = math::ceil_div_usize(self.bits, WORD_BITS_USIZE);
+        let mut words = Buffer::allocate(len);
+        words.push_zeros(len);
+        // Runs alternate between 1s and 0s, starting with 1s at the top.
+        let mut end = self.bits;
+        let mut ones = true;
+        while end > 0 {
+            // Run lengths are roughly log-uniform.
+            let max_len = 1usize << rng.gen_range(0..=math::bit_len(end) - 1);
+            let run = rng.gen_range(1..=max_len);
+            let start = end - run;
+            if ones {
+                set_ones(&mut words, start, end);
+            }
+            end = start;
+            ones = !ones;
+        }
+        words.into()
+    }
+}
+
+impl ModuloRing {
+    /// Uniformly random element of the ring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{modular::ModuloRing, ubig};
+    /// use rand::thread_rng;
+    /// let ring = ModuloRing::new(&ubig!(100));
+    /// let x = ring.random(&mut thread_rng());
+    /// assert!(x.residue() < ubig!(100));
+    /// ```
+    #[inline]
+    pub fn random<R>(&self, rng: &mut R) -> Modulo<'_>
+    where
+        R: Rng + ?Sized,
+    {
+        // x is uniform in [0, n) iff x << shift is uniform in [0, n << shift) with the low shift
+        // bits cleared, so sample directly below the normalized modulus.
+        match self.repr() {
+            ModuloRingRepr::Small(ring) => {
+                let normalized =
+                    rng.gen_range(0..ring.normalized_modulus()) & !math::ones_word(ring.shift());
+                ModuloSmall::new(ModuloSmallRaw::from_normalized(normalized), ring).into()
+            }
+            ModuloRingRepr::Large(ring) => {
+                let modulus = ring.normalized_modulus();
+                let mut normalized = vec![0; modulus.len()];
+                while !try_fill_uniform(modulus, rng, &mut normalized) {
+                    // Repeat.
+                }
+                normalized[0] &= !math::ones_word(ring.shift());
+                ModuloLarge::new(normalized, ring).into()
+            }
+        }
+    }
+
+    /// Uniformly random invertible element of the ring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{modular::ModuloRing, ubig};
+    /// use rand::thread_rng;
+    /// let ring = ModuloRing::new(&ubig!(100));
+    /// let x = ring.random_unit(&mut thread_rng());
+    /// assert!(x.inverse().is_some());
+    /// ```
+    pub fn random_unit<R>(&self, rng: &mut R) -> Modulo<'_>
+    where
+        R: Rng + ?Sized,
+    {
+        let modulus = self.modulus();
+        loop {
+            // phi(n) / n is at least about 1 / (1.8 ln ln n), so few attempts are needed.
+            let x = self.random(rng);
+            if x.residue().gcd(&modulus) == UBig::from_word(1) {
+                return x;
+            }
+        }
+    }
+}
+
+/// Uniformly random elements of a [ModuloRing].
+///
+/// # Example
+///
+/// ```
+/// # use ibig::{modular::ModuloRing, ubig};
+/// use rand::{thread_rng, Rng};
+/// let ring = ModuloRing::new(&ubig!(100));
+/// let x = thread_rng().sample(&ring);
+/// assert!(x.residue() < ubig!(100));
+/// ```
+impl<'a> Distribution<Modulo<'a>> for &'a ModuloRing {
+    #[inline]
+    fn sample<R>(&self, rng: &mut R) -> Modulo<'a>
+    where
+        R: Rng + ?Sized,
+    {
+        self.random(rng)
+    }
+}
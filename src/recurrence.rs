@@ -0,0 +1,217 @@
+//! Fibonacci numbers, Lucas sequences and second-order linear recurrences.
+
+use crate::{
+    ibig::IBig,
+    math,
+    modular::{Modulo, ModuloRing},
+    ubig::UBig,
+};
+use core::ops::{Add, Mul, Sub};
+
+impl UBig {
+    /// The `n`-th Fibonacci number.
+    ///
+    /// F(0) = 0, F(1) = 1, F(n) = F(n-1) + F(n-2).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::fibonacci(10), ubig!(55));
+    /// ```
+    pub fn fibonacci(n: usize) -> UBig {
+        fibonacci_pair(n).0
+    }
+
+    /// The `n`-th Lucas number.
+    ///
+    /// L(0) = 2, L(1) = 1, L(n) = L(n-1) + L(n-2).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::lucas(10), ubig!(123));
+    /// ```
+    pub fn lucas(n: usize) -> UBig {
+        // L(n) = 2 F(n+1) - F(n)
+        let (f, f_next) = fibonacci_pair(n);
+        (f_next << 1) - f
+    }
+}
+
+impl IBig {
+    /// The Lucas sequences `(U(n), V(n))` with parameters `p` and `q`.
+    ///
+    /// * U(0) = 0, U(1) = 1, U(n) = p U(n-1) - q U(n-2)
+    /// * V(0) = 2, V(1) = p, V(n) = p V(n-1) - q V(n-2)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// // Mersenne numbers and powers of 2 plus 1.
+    /// assert_eq!(
+    ///     IBig::lucas_sequence(10, &ibig!(3), &ibig!(2)),
+    ///     (ibig!(1023), ibig!(1025))
+    /// );
+    /// ```
+    pub fn lucas_sequence(n: usize, p: &IBig, q: &IBig) -> (IBig, IBig) {
+        let (u, u_next) = lucas_u_pair(&UBig::from(n), p, q, IBig::from(0u8), IBig::from(1u8));
+        // V(n) = 2 U(n+1) - p U(n)
+        let v = &u_next + &u_next - p * &u;
+        (u, v)
+    }
+
+    /// The `n`-th term of the linear recurrence `x(n) = a x(n-1) + b x(n-2)`, given `x(0)` and
+    /// `x(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{ibig, IBig};
+    /// // Pell numbers.
+    /// assert_eq!(
+    ///     IBig::linear_recurrence(10, &ibig!(2), &ibig!(1), &ibig!(0), &ibig!(1)),
+    ///     ibig!(2378)
+    /// );
+    /// ```
+    pub fn linear_recurrence(n: usize, a: &IBig, b: &IBig, x0: &IBig, x1: &IBig) -> IBig {
+        let (u, u_next) = lucas_u_pair(&UBig::from(n), a, &-b, IBig::from(0u8), IBig::from(1u8));
+        // x(n) = (x(1) - a x(0)) U(n) + x(0) U(n+1)
+        (x1 - a * x0) * u + x0 * u_next
+    }
+}
+
+impl ModuloRing {
+    /// The `n`-th Fibonacci number in the ring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{modular::ModuloRing, ubig};
+    /// let ring = ModuloRing::new(&ubig!(1000));
+    /// assert_eq!(ring.fibonacci(20), ring.from(6765));
+    /// ```
+    pub fn fibonacci(&self, n: usize) -> Modulo<'_> {
+        let one = self.from(1u8);
+        lucas_u_pair(&UBig::from(n), &one, &-&one, self.from(0u8), one.clone()).0
+    }
+
+    /// The `n`-th Lucas number in the ring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{modular::ModuloRing, ubig};
+    /// let ring = ModuloRing::new(&ubig!(1000));
+    /// assert_eq!(ring.lucas(20), ring.from(15127));
+    /// ```
+    pub fn lucas(&self, n: usize) -> Modulo<'_> {
+        self.lucas_sequence(n, &self.from(1u8), &self.from(-1)).1
+    }
+
+    /// The Lucas sequences `(U(n), V(n))` in the ring with parameters `p` and `q`.
+    ///
+    /// See [IBig::lucas_sequence].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{modular::ModuloRing, ubig};
+    /// let ring = ModuloRing::new(&ubig!(1000));
+    /// assert_eq!(
+    ///     ring.lucas_sequence(10, &ring.from(3), &ring.from(2)),
+    ///     (ring.from(23), ring.from(25))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` or `q` belong to a different ring.
+    pub fn lucas_sequence<'a>(
+        &'a self,
+        n: usize,
+        p: &Modulo<'a>,
+        q: &Modulo<'a>,
+    ) -> (Modulo<'a>, Modulo<'a>) {
+        let (u, u_next) = lucas_u_pair(&UBig::from(n), p, q, self.from(0u8), self.from(1u8));
+        let v = &u_next + &u_next - p * &u;
+        (u, v)
+    }
+
+    /// The `n`-th term of the linear recurrence `x(n) = a x(n-1) + b x(n-2)` in the ring,
+    /// given `x(0)` and `x(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::{modular::ModuloRing, ubig};
+    /// let ring = ModuloRing::new(&ubig!(1000));
+    /// let (a, b, x0, x1) = (ring.from(2), ring.from(1), ring.from(0), ring.from(1));
+    /// assert_eq!(ring.linear_recurrence(10, &a, &b, &x0, &x1), ring.from(378));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the arguments belongs to a different ring.
+    pub fn linear_recurrence<'a>(
+        &'a self,
+        n: usize,
+        a: &Modulo<'a>,
+        b: &Modulo<'a>,
+        x0: &Modulo<'a>,
+        x1: &Modulo<'a>,
+    ) -> Modulo<'a> {
+        let (u, u_next) = lucas_u_pair(&UBig::from(n), a, &-b, self.from(0u8), self.from(1u8));
+        (x1 - a * x0) * u + x0 * u_next
+    }
+}
+
+/// (F(n), F(n+1))
+fn fibonacci_pair(n: usize) -> (UBig, UBig) {
+    // Invariant: (f, f_next) = (F(k), F(k+1)) where k is the prefix of the bits of n.
+    let mut f = UBig::from_word(0);
+    let mut f_next = UBig::from_word(1);
+    for i in (0..math::bit_len(n)).rev() {
+        // F(2k) = F(k) (2 F(k+1) - F(k))
+        // F(2k+1) = F(k)^2 + F(k+1)^2
+        let f_double = &f * ((&f_next << 1) - &f);
+        let f_double_next = &f * &f + &f_next * &f_next;
+        if n >> i & 1 == 0 {
+            f = f_double;
+            f_next = f_double_next;
+        } else {
+            f_next = &f_double + &f_double_next;
+            f = f_double_next;
+        }
+    }
+    (f, f_next)
+}
+
+/// (U(n), U(n+1)) for the Lucas sequence U with parameters p and q.
+///
+/// Works in any commutative ring.
+pub(crate) fn lucas_u_pair<T>(n: &UBig, p: &T, q: &T, zero: T, one: T) -> (T, T)
+where
+    for<'x> &'x T: Add<&'x T, Output = T> + Sub<&'x T, Output = T> + Mul<&'x T, Output = T>,
+{
+    // Invariant: (u, u_next) = (U(k), U(k+1)) where k is the prefix of the bits of n.
+    let mut u = zero;
+    let mut u_next = one;
+    for i in (0..n.bit_len()).rev() {
+        // U(2k) = U(k) (2 U(k+1) - p U(k))
+        // U(2k+1) = U(k+1)^2 - q U(k)^2
+        let u_double = &u * &(&(&u_next + &u_next) - &(p * &u));
+        let u_double_next = &(&u_next * &u_next) - &(q * &(&u * &u));
+        if !n.bit(i) {
+            u = u_double;
+            u_next = u_double_next;
+        } else {
+            // U(2k+2) = p U(2k+1) - q U(2k)
+            u_next = &(p * &u_double_next) - &(q * &u_double);
+            u = u_double_next;
+        }
+    }
+    (u, u_next)
+}
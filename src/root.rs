@@ -0,0 +1,140 @@
+//! Integer roots.
+
+use crate::{
+    ibig::IBig,
+    math,
+    sign::Sign::*,
+    ubig::{Repr::*, UBig},
+};
+
+/// Extra bits of the root, beyond 2 * bit_len(n), below which bisection is used.
+///
+/// Newton's method converges quickly only once the relative error is well below 1/n.
+const ROOT_BITS_BISECTION_MARGIN: usize = 8;
+
+impl UBig {
+    /// Square root, rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(35).sqrt(), ubig!(5));
+    /// assert_eq!(ubig!(36).sqrt(), ubig!(6));
+    /// ```
+    #[inline]
+    pub fn sqrt(&self) -> UBig {
+        self.nth_root(2)
+    }
+
+    /// `n`-th root, rounded down.
+    ///
+    /// The result `r` satisfies `r^n <= self < (r+1)^n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(124).nth_root(3), ubig!(4));
+    /// assert_eq!(ubig!(125).nth_root(3), ubig!(5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn nth_root(&self, n: usize) -> UBig {
+        match n {
+            0 => panic!("0th root"),
+            1 => return self.clone(),
+            _ => {}
+        }
+        match self.repr() {
+            Small(0) | Small(1) => return self.clone(),
+            _ => {}
+        }
+        let bits = self.bit_len();
+        if n >= bits {
+            // 1 < self < 2^n
+            return UBig::from_word(1);
+        }
+
+        let root_bits = math::ceil_div(bits, n);
+        // 2^(root_bits - 1) <= root < 2^root_bits
+        if root_bits <= 2 * math::bit_len(n) as usize + ROOT_BITS_BISECTION_MARGIN {
+            return self.nth_root_bisection(n, root_bits);
+        }
+
+        // Initial approximation r >= root:
+        // root(self) < root(top + 1) * 2^k <= (root(top) + 1) * 2^k
+        // where top = floor(self / 2^(n*k)).
+        //
+        // This has about half the bits correct, so a few Newton steps are enough.
+        let k = root_bits / 2;
+        let mut r = ((self >> (n * k)).nth_root(n) + UBig::from_word(1)) << k;
+
+        // Newton's method from above: r decreases until it reaches the root.
+        loop {
+            let next = (&r * (n - 1) + self / r.pow(n - 1)) / n;
+            if next >= r {
+                return r;
+            }
+            r = next;
+        }
+    }
+
+    /// `n`-th root by bisection, given that 2^(root_bits - 1) <= root < 2^root_bits.
+    fn nth_root_bisection(&self, n: usize, root_bits: usize) -> UBig {
+        // lo <= root < hi
+        let mut lo = UBig::from_word(1) << (root_bits - 1);
+        let mut hi = UBig::from_word(1) << root_bits;
+        let one = UBig::from_word(1);
+        while &lo + &one < hi {
+            let mid = (&lo + &hi) >> 1;
+            if mid.pow(n) <= *self {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+impl IBig {
+    /// Square root, rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(35).sqrt(), ibig!(5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    #[inline]
+    pub fn sqrt(&self) -> IBig {
+        self.nth_root(2)
+    }
+
+    /// `n`-th root, rounded towards zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ibig::ibig;
+    /// assert_eq!(ibig!(124).nth_root(3), ibig!(4));
+    /// assert_eq!(ibig!(-124).nth_root(3), ibig!(-4));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, or if `n` is even and `self` is negative.
+    pub fn nth_root(&self, n: usize) -> IBig {
+        if self.sign() == Negative && n % 2 == 0 {
+            panic!("even root of a negative number");
+        }
+        IBig::from_sign_magnitude(self.sign(), self.magnitude().nth_root(n))
+    }
+}
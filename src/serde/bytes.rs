@@ -0,0 +1,22 @@
+//! Big-endian byte representation.
+//!
+//! [UBig](crate::UBig) is represented by its minimal big-endian bytes, with no bytes for zero.
+//! [IBig](crate::IBig) is represented in minimal two's complement.
+//!
+//! Deserialization accepts both bytes and sequences of bytes.
+
+use super::{BytesVisitor, SerdeInteger};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Serialize as big-endian bytes.
+pub fn serialize<T: SerdeInteger, S: Serializer>(x: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(&x.to_bytes())
+}
+
+/// Deserialize from big-endian bytes.
+pub fn deserialize<'de, T: SerdeInteger, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+}
@@ -0,0 +1,34 @@
+//! The default representation, rejecting non-canonical input.
+//!
+//! Serialization is the same as the default. Deserialization fails on leading zero words, and on
+//! a negative sign with zero magnitude, so that every value has exactly one accepted encoding.
+//!
+//! ```
+//! # use ibig::ubig;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Data {
+//!     #[serde(with = "ibig::serde::canonical")]
+//!     x: ibig::UBig,
+//! }
+//!
+//! assert_eq!(serde_json::from_str::<Data>(r#"{"x":[5]}"#)?, Data { x: ubig!(5) });
+//! assert!(serde_json::from_str::<Data>(r#"{"x":[5,0]}"#).is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use super::SerdeInteger;
+use serde::{Deserializer, Serializer};
+
+/// Serialize in the default representation.
+pub fn serialize<T: SerdeInteger, S: Serializer>(x: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    x.serialize(serializer)
+}
+
+/// Deserialize from the default representation in canonical form.
+pub fn deserialize<'de, T: SerdeInteger, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::deserialize_canonical(deserializer)
+}
@@ -0,0 +1,26 @@
+//! A decimal string in human-readable formats, big-endian bytes otherwise.
+//!
+//! See [decimal](super::decimal) and [bytes](super::bytes).
+
+use super::SerdeInteger;
+use serde::{Deserializer, Serializer};
+
+/// Serialize as a decimal string or as big-endian bytes, depending on the format.
+pub fn serialize<T: SerdeInteger, S: Serializer>(x: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        super::decimal::serialize(x, serializer)
+    } else {
+        super::bytes::serialize(x, serializer)
+    }
+}
+
+/// Deserialize from a decimal string or from big-endian bytes, depending on the format.
+pub fn deserialize<'de, T: SerdeInteger, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    if deserializer.is_human_readable() {
+        super::decimal::deserialize(deserializer)
+    } else {
+        super::bytes::deserialize(deserializer)
+    }
+}
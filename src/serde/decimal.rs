@@ -0,0 +1,36 @@
+//! Decimal string representation.
+//!
+//! Deserialization also accepts integer primitives in human-readable formats.
+//!
+//! ```
+//! # use ibig::ubig;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Data {
+//!     #[serde(with = "ibig::serde::decimal")]
+//!     x: ibig::UBig,
+//! }
+//!
+//! let data = Data { x: ubig!(1) << 100 };
+//! assert_eq!(
+//!     serde_json::to_string(&data)?,
+//!     r#"{"x":"1267650600228229401496703205376"}"#
+//! );
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use super::SerdeInteger;
+use serde::{Deserializer, Serializer};
+
+/// Serialize as a decimal string.
+pub fn serialize<T: SerdeInteger, S: Serializer>(x: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(x)
+}
+
+/// Deserialize from a decimal string.
+pub fn deserialize<'de, T: SerdeInteger, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    super::deserialize_str(deserializer, 10)
+}
@@ -0,0 +1,44 @@
+//! Hexadecimal string representation, such as `"0xff"` or `"-0xff"`.
+//!
+//! Deserialization accepts an optional `0x` prefix, and also integer primitives in
+//! human-readable formats.
+//!
+//! ```
+//! # use ibig::ibig;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Data {
+//!     #[serde(with = "ibig::serde::hex")]
+//!     x: ibig::IBig,
+//! }
+//!
+//! let data = Data { x: ibig!(-255) };
+//! assert_eq!(serde_json::to_string(&data)?, r#"{"x":"-0xff"}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use super::SerdeInteger;
+use core::fmt::{self, Display, Formatter, LowerHex};
+use serde::{Deserializer, Serializer};
+
+/// Serialize as a hexadecimal string with a `0x` prefix.
+pub fn serialize<T: SerdeInteger, S: Serializer>(x: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&Hex(x))
+}
+
+/// Deserialize from a hexadecimal string, with or without a `0x` prefix.
+pub fn deserialize<'de, T: SerdeInteger, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    super::deserialize_str(deserializer, 16)
+}
+
+/// Display with the `0x` prefix.
+struct Hex<'a, T>(&'a T);
+
+impl<T: LowerHex> Display for Hex<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
@@ -0,0 +1,361 @@
+//! Serialization and deserialization with serde.
+//!
+//! By default, [UBig](crate::UBig) is serialized as a sequence of 64-bit words, least significant
+//! first, and [IBig](crate::IBig) as a pair of its sign and magnitude.
+//!
+//! The modules here provide other representations, for use with `#[serde(with = "...")]`.
+//! They work with both [UBig](crate::UBig) and [IBig](crate::IBig):
+//! * [decimal]: a decimal string.
+//! * [hex]: a hexadecimal string with a `0x` prefix.
+//! * [bytes]: big-endian bytes, two's complement for [IBig](crate::IBig).
+//! * [compact]: a decimal string in human-readable formats, bytes otherwise.
+//!
+//! # Example
+//!
+//! ```
+//! use ibig::{ibig, ubig, IBig, UBig};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Data {
+//!     #[serde(with = "ibig::serde::decimal")]
+//!     a: UBig,
+//!     #[serde(with = "ibig::serde::hex")]
+//!     b: IBig,
+//! }
+//!
+//! let data = Data {
+//!     a: ubig!(12345678901234567890123),
+//!     b: ibig!(-0xff),
+//! };
+//! let json = serde_json::to_string(&data)?;
+//! assert_eq!(json, r#"{"a":"12345678901234567890123","b":"-0xff"}"#);
+//! assert_eq!(serde_json::from_str::<Data>(&json)?, data);
+//! // Numbers are also accepted.
+//! assert_eq!(
+//!     serde_json::from_str::<Data>(r#"{"a":5,"b":-3}"#)?,
+//!     Data { a: ubig!(5), b: ibig!(-3) }
+//! );
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{error::ParseError, ibig::IBig, sign::Sign::*, ubig::UBig};
+use alloc::vec::Vec;
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter, LowerHex},
+    marker::PhantomData,
+};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+pub mod bytes;
+pub mod canonical;
+pub mod compact;
+pub mod decimal;
+pub mod hex;
+
+impl Serialize for UBig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let words_64 = self.as_u64_slice();
+        let mut seq = serializer.serialize_seq(Some(words_64.len()))?;
+        for word_64 in words_64.iter() {
+            seq.serialize_element(word_64)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for UBig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(UBigVisitor { canonical: false })
+    }
+}
+
+/// Maximum number of 64-bit words preallocated based on a size hint.
+///
+/// Size hints come from untrusted input, so larger sequences grow the allocation as elements
+/// actually arrive.
+const MAX_PREALLOCATE_LEN_64: usize = 1 << 10;
+
+/// Maximum number of 64-bit words in a [UBig].
+const MAX_LEN_64: usize = (UBig::MAX_BIT_LEN + 63) / 64;
+
+struct UBigVisitor {
+    /// Reject leading zero words.
+    canonical: bool,
+}
+
+impl<'de> Visitor<'de> for UBigVisitor {
+    type Value = UBig;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        if self.canonical {
+            write!(f, "a sequence of 64-bit words with no leading zeros")
+        } else {
+            write!(f, "a sequence of 64-bit words")
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<UBig, A::Error> {
+        let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATE_LEN_64);
+        let mut words_64 = Vec::with_capacity(capacity);
+        while let Some(word_64) = seq.next_element::<u64>()? {
+            if words_64.len() == MAX_LEN_64 {
+                return Err(de::Error::invalid_length(MAX_LEN_64 + 1, &self));
+            }
+            words_64.push(word_64);
+        }
+        match words_64.last() {
+            Some(0) if self.canonical => {
+                return Err(de::Error::invalid_value(Unexpected::Unsigned(0), &self));
+            }
+            _ => {}
+        }
+        while let Some(0) = words_64.last() {
+            words_64.pop();
+        }
+        if let Some(&top) = words_64.last() {
+            let bit_len = (words_64.len() - 1) * 64 + (64 - top.leading_zeros() as usize);
+            if bit_len > UBig::MAX_BIT_LEN {
+                return Err(de::Error::invalid_length(words_64.len(), &self));
+            }
+        }
+        Ok(UBig::from_u64_slice(&words_64))
+    }
+}
+
+impl Serialize for IBig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.sign(), self.magnitude()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IBig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (sign, magnitude) = Deserialize::deserialize(deserializer)?;
+        Ok(IBig::from_sign_magnitude(sign, magnitude))
+    }
+}
+
+/// Integer types supported by the `serde(with = ...)` modules: [UBig] and [IBig].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SerdeInteger: private::Sealed {}
+
+impl SerdeInteger for UBig {}
+
+impl SerdeInteger for IBig {}
+
+mod private {
+    use super::*;
+
+    pub trait Sealed: Sized + Display + LowerHex + Serialize {
+        /// `None` if the value is out of range.
+        fn from_sign_magnitude(negative: bool, magnitude: UBig) -> Option<Self>;
+
+        fn from_i128(x: i128) -> Option<Self>;
+
+        fn from_u128(x: u128) -> Self;
+
+        /// Big-endian bytes.
+        fn to_bytes(&self) -> Vec<u8>;
+
+        fn from_bytes(bytes: &[u8]) -> Self;
+
+        /// Deserialize the default representation, rejecting non-canonical forms.
+        fn deserialize_canonical<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error>;
+    }
+
+    impl Sealed for UBig {
+        fn from_sign_magnitude(negative: bool, magnitude: UBig) -> Option<UBig> {
+            if negative && magnitude != UBig::from_word(0) {
+                None
+            } else {
+                Some(magnitude)
+            }
+        }
+
+        fn from_i128(x: i128) -> Option<UBig> {
+            UBig::try_from(x).ok()
+        }
+
+        fn from_u128(x: u128) -> UBig {
+            UBig::from(x)
+        }
+
+        fn to_bytes(&self) -> Vec<u8> {
+            self.to_be_bytes()
+        }
+
+        fn from_bytes(bytes: &[u8]) -> UBig {
+            UBig::from_be_bytes(bytes)
+        }
+
+        fn deserialize_canonical<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<UBig, D::Error> {
+            deserializer.deserialize_seq(UBigVisitor { canonical: true })
+        }
+    }
+
+    impl Sealed for IBig {
+        fn from_sign_magnitude(negative: bool, magnitude: UBig) -> Option<IBig> {
+            let sign = if negative { Negative } else { Positive };
+            Some(IBig::from_sign_magnitude(sign, magnitude))
+        }
+
+        fn from_i128(x: i128) -> Option<IBig> {
+            Some(IBig::from(x))
+        }
+
+        fn from_u128(x: u128) -> IBig {
+            IBig::from(x)
+        }
+
+        fn to_bytes(&self) -> Vec<u8> {
+            self.to_signed_be_bytes()
+        }
+
+        fn from_bytes(bytes: &[u8]) -> IBig {
+            IBig::from_signed_be_bytes(bytes)
+        }
+
+        fn deserialize_canonical<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<IBig, D::Error> {
+            let (sign, CanonicalUBig(magnitude)) = Deserialize::deserialize(deserializer)?;
+            if sign == Negative && magnitude == UBig::from_word(0) {
+                return Err(de::Error::invalid_value(
+                    Unexpected::Other("negative zero"),
+                    &"an integer in canonical form",
+                ));
+            }
+            Ok(IBig::from_sign_magnitude(sign, magnitude))
+        }
+    }
+}
+
+/// [UBig] deserialized in canonical form.
+struct CanonicalUBig(UBig);
+
+impl<'de> Deserialize<'de> for CanonicalUBig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = UBigVisitor { canonical: true };
+        deserializer.deserialize_seq(visitor).map(CanonicalUBig)
+    }
+}
+
+/// Parse a string with an optional sign in a given radix.
+///
+/// For radix 16, a `0x` prefix is allowed after the sign.
+fn parse<T: SerdeInteger>(src: &str, radix: u32) -> Result<Result<T, ParseError>, ()> {
+    let (negative, mut digits) = match src.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, src.strip_prefix('+').unwrap_or(src)),
+    };
+    if radix == 16 {
+        digits = digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))
+            .unwrap_or(digits);
+    }
+    if digits.starts_with('+') {
+        return Ok(Err(ParseError::InvalidDigit));
+    }
+    let magnitude = match UBig::from_str_radix(digits, radix) {
+        Ok(magnitude) => magnitude,
+        Err(err) => return Ok(Err(err)),
+    };
+    T::from_sign_magnitude(negative, magnitude)
+        .map(Ok)
+        .ok_or(())
+}
+
+/// Visitor for strings in a given radix, and for integer primitives.
+struct StrVisitor<T> {
+    radix: u32,
+    marker: PhantomData<T>,
+}
+
+impl<T> StrVisitor<T> {
+    fn new(radix: u32) -> StrVisitor<T> {
+        StrVisitor {
+            radix,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: SerdeInteger> Visitor<'de> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "an integer or a string of base {} digits", self.radix)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        match parse(v, self.radix) {
+            Ok(Ok(x)) => Ok(x),
+            Ok(Err(err)) => Err(E::custom(err)),
+            Err(()) => Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        T::from_i128(v.into()).ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        Ok(T::from_u128(v.into()))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<T, E> {
+        T::from_i128(v)
+            .ok_or_else(|| E::invalid_value(Unexpected::Other("negative integer"), &self))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+        Ok(T::from_u128(v))
+    }
+}
+
+/// Deserialize a string in a given radix. Human-readable formats may also provide a number.
+fn deserialize_str<'de, T, D>(deserializer: D, radix: u32) -> Result<T, D::Error>
+where
+    T: SerdeInteger,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(StrVisitor::new(radix))
+    } else {
+        deserializer.deserialize_str(StrVisitor::new(radix))
+    }
+}
+
+/// Visitor for bytes, or a sequence of bytes.
+struct BytesVisitor<T>(PhantomData<T>);
+
+impl<'de, T: SerdeInteger> Visitor<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "big-endian bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        Ok(T::from_bytes(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(T::from_bytes(&bytes))
+    }
+}
@@ -1,9 +1,10 @@
-//! Bit shift operators.
+//! Bit shift operators and fixed-width bit operations.
 
 use crate::{
     arch::word::Word,
     buffer::Buffer,
     ibig::IBig,
+    math,
     primitive::{double_word, extend_word, split_double_word, WORD_BITS_USIZE},
     shift,
     sign::Sign::*,
@@ -188,6 +189,193 @@ impl Shr<usize> for &IBig {
 }
 
 impl UBig {
+    /// The lowest `width` bits, i.e. the number modulo `2^width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b110101).truncate_bits(4), ubig!(0b0101));
+    /// assert_eq!(ubig!(0b110101).truncate_bits(100), ubig!(0b110101));
+    /// ```
+    #[inline]
+    pub fn truncate_bits(&self, width: usize) -> UBig {
+        self.bits(..width)
+    }
+
+    /// Split into the bits below bit `k` and the bits from bit `k` up.
+    ///
+    /// Returns `(low, high)` such that `self == low + (high << k)` and `low < 2^k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0x12345).split_at_bit(8), (ubig!(0x45), ubig!(0x123)));
+    /// ```
+    #[inline]
+    pub fn split_at_bit(&self, k: usize) -> (UBig, UBig) {
+        match self.repr() {
+            Small(word) => UBig::split_word_at_bit(*word, k),
+            Large(buffer) => UBig::split_large_at_bit(buffer, k),
+        }
+    }
+
+    /// Split into the bits below bit `k` and the bits from bit `k` up, reusing the memory of
+    /// `self`.
+    ///
+    /// Returns `(low, high)` such that `self == low + (high << k)` and `low < 2^k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0x12345).into_split_at_bit(8), (ubig!(0x45), ubig!(0x123)));
+    /// ```
+    #[inline]
+    pub fn into_split_at_bit(self, k: usize) -> (UBig, UBig) {
+        match self.into_repr() {
+            Small(word) => UBig::split_word_at_bit(word, k),
+            Large(buffer) => UBig::into_split_large_at_bit(buffer, k),
+        }
+    }
+
+    /// Rotate left by `n` bits within a width of `width` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number doesn't fit in `width` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1001_0110).rotate_left(3, 8), ubig!(0b1011_0100));
+    /// assert_eq!(ubig!(0b1001_0110).rotate_left(11, 8), ubig!(0b1011_0100));
+    /// ```
+    pub fn rotate_left(&self, n: usize, width: usize) -> UBig {
+        self.assert_fits_in(width);
+        if width == 0 {
+            return UBig::from_word(0);
+        }
+        let n = n % width;
+        let (low, high) = self.split_at_bit(width - n);
+        (low << n) | high
+    }
+
+    /// Rotate right by `n` bits within a width of `width` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number doesn't fit in `width` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b1001_0110).rotate_right(3, 8), ubig!(0b1101_0010));
+    /// ```
+    pub fn rotate_right(&self, n: usize, width: usize) -> UBig {
+        self.assert_fits_in(width);
+        if width == 0 {
+            return UBig::from_word(0);
+        }
+        self.rotate_left(width - n % width, width)
+    }
+
+    /// Reverse the order of the lowest `width` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number doesn't fit in `width` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::ubig;
+    /// assert_eq!(ubig!(0b0001_0110).reverse_bits(8), ubig!(0b0110_1000));
+    /// assert_eq!(ubig!(0b1).reverse_bits(100), ubig!(1) << 99);
+    /// ```
+    pub fn reverse_bits(&self, width: usize) -> UBig {
+        self.assert_fits_in(width);
+        let words = self.as_words();
+        let len = math::ceil_div_usize(width, WORD_BITS_USIZE);
+        let mut buffer = Buffer::allocate(len);
+        buffer.push_zeros(len - words.len().min(len));
+        buffer.extend(
+            words[..words.len().min(len)]
+                .iter()
+                .rev()
+                .map(|word| word.reverse_bits()),
+        );
+        // Reversed within len words, now align to width.
+        shift::shr_in_place(&mut buffer, (len * WORD_BITS_USIZE - width) as u32);
+        buffer.into()
+    }
+
+    /// Split one `Word` at bit `k`.
+    #[inline]
+    fn split_word_at_bit(word: Word, k: usize) -> (UBig, UBig) {
+        if k < WORD_BITS_USIZE {
+            (
+                UBig::from_word(word & math::ones_word(k as u32)),
+                UBig::from_word(word >> k),
+            )
+        } else {
+            (UBig::from_word(word), UBig::from_word(0))
+        }
+    }
+
+    /// Split a large number of words at bit `k`.
+    fn split_large_at_bit(words: &[Word], k: usize) -> (UBig, UBig) {
+        let idx = k / WORD_BITS_USIZE;
+        if idx >= words.len() {
+            let mut buffer = Buffer::allocate(words.len());
+            buffer.extend(words);
+            return (buffer.into(), UBig::from_word(0));
+        }
+        let shift_bits = (k % WORD_BITS_USIZE) as u32;
+        let mut low = Buffer::allocate(idx + 1);
+        low.extend(&words[..=idx]);
+        let mut high = Buffer::allocate(words.len() - idx);
+        high.extend(&words[idx..]);
+        UBig::finish_split(low, high, shift_bits)
+    }
+
+    /// Split `buffer` at bit `k`.
+    ///
+    /// The longer part stays in `buffer`, only the shorter one is copied.
+    fn into_split_large_at_bit(mut buffer: Buffer, k: usize) -> (UBig, UBig) {
+        let idx = k / WORD_BITS_USIZE;
+        if idx >= buffer.len() {
+            return (buffer.into(), UBig::from_word(0));
+        }
+        let shift_bits = (k % WORD_BITS_USIZE) as u32;
+        if idx < buffer.len() - idx {
+            let mut low = Buffer::allocate(idx + 1);
+            low.extend(&buffer[..=idx]);
+            buffer.erase_front(idx);
+            UBig::finish_split(low, buffer, shift_bits)
+        } else {
+            let mut high = Buffer::allocate(buffer.len() - idx);
+            high.extend(&buffer[idx..]);
+            buffer.truncate(idx + 1);
+            UBig::finish_split(buffer, high, shift_bits)
+        }
+    }
+
+    /// Both `low` and `high` start with the word containing the split point, which is
+    /// `shift_bits` into that word.
+    fn finish_split(mut low: Buffer, mut high: Buffer, shift_bits: u32) -> (UBig, UBig) {
+        *low.last_mut().unwrap() &= math::ones_word(shift_bits);
+        shift::shr_in_place(&mut high, shift_bits);
+        (low.into(), high.into())
+    }
+
+    fn assert_fits_in(&self, width: usize) {
+        assert!(self.bit_len() <= width, "number doesn't fit in width");
+    }
+
     /// Shift left one non-zero `Word` by `rhs` bits.
     #[inline]
     fn shl_word(word: Word, rhs: usize) -> UBig {
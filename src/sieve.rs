@@ -0,0 +1,47 @@
+//! Sieve of Eratosthenes.
+
+use alloc::{vec, vec::Vec};
+
+/// All primes `p <= n`, in increasing order.
+pub(crate) fn primes_up_to(n: usize) -> Vec<usize> {
+    if n < 2 {
+        return Vec::new();
+    }
+    // composite[i] says whether 2i+1 is composite.
+    let len = (n - 1) / 2 + 1;
+    let mut composite = vec![false; len];
+    let mut primes = vec![2];
+    for i in 1..len {
+        if composite[i] {
+            continue;
+        }
+        let p = 2 * i + 1;
+        primes.push(p);
+        if p > n / p {
+            continue;
+        }
+        // Odd multiples of p, starting at p^2 = 2(2i^2+2i)+1.
+        let mut j = 2 * i * (i + 1);
+        while j < len {
+            composite[j] = true;
+            j += p;
+        }
+    }
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primes_up_to() {
+        assert!(primes_up_to(0).is_empty());
+        assert!(primes_up_to(1).is_empty());
+        assert_eq!(primes_up_to(2), [2]);
+        assert_eq!(primes_up_to(3), [2, 3]);
+        assert_eq!(primes_up_to(30), [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_up_to(31).last(), Some(&31));
+        assert_eq!(primes_up_to(100000).len(), 9592);
+    }
+}
@@ -5,8 +5,9 @@ use crate::{
     arch::{ntt, word::Word},
     buffer::Buffer,
     math,
-    primitive::WORD_BITS_USIZE,
+    primitive::{WORD_BITS, WORD_BITS_USIZE},
 };
+use alloc::{vec, vec::Vec};
 use core::slice;
 
 /// Internal representation of UBig.
@@ -80,6 +81,88 @@ impl UBig {
         }
     }
 
+    /// Number of bits in a [Word].
+    pub const WORD_BITS: u32 = WORD_BITS;
+
+    /// Construct from limbs, least significant first.
+    ///
+    /// Leading zero limbs are allowed. The allocation of `limbs` is reused if its capacity is not
+    /// much larger than the length of the number and no [custom allocator](crate::allocator) is
+    /// set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// assert_eq!(UBig::from_limbs(vec![5, 0]), ubig!(5));
+    /// assert_eq!(UBig::from_limbs(vec![0, 1]), ubig!(1) << UBig::WORD_BITS as usize);
+    /// assert_eq!(UBig::from_limbs(vec![]), ubig!(0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is too large.
+    pub fn from_limbs(limbs: Vec<Word>) -> UBig {
+        // Small numbers don't need memory, so don't adopt the allocation.
+        match limbs.iter().rposition(|&word| word != 0) {
+            None => UBig::from_word(0),
+            Some(0) => UBig::from_word(limbs[0]),
+            Some(_) => Buffer::from_vec(limbs).into(),
+        }
+    }
+
+    /// Limbs, least significant first, with no leading zeros.
+    ///
+    /// The limbs are the number's internal representation, so this doesn't copy. Zero has no limbs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let x = (ubig!(1) << UBig::WORD_BITS as usize) + ubig!(5);
+    /// assert_eq!(x.as_limbs(), [5, 1]);
+    /// assert!(ubig!(0).as_limbs().is_empty());
+    /// ```
+    #[inline]
+    pub fn as_limbs(&self) -> &[Word] {
+        self.as_words()
+    }
+
+    /// Iterator over the limbs, least significant first, with no leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let x = (ubig!(1) << UBig::WORD_BITS as usize) + ubig!(5);
+    /// assert_eq!(x.limbs().rev().collect::<Vec<_>>(), [1, 5]);
+    /// ```
+    #[inline]
+    pub fn limbs(&self) -> impl DoubleEndedIterator<Item = Word> + ExactSizeIterator + '_ {
+        self.as_words().iter().copied()
+    }
+
+    /// Convert into limbs, least significant first, with no leading zeros.
+    ///
+    /// Reuses the allocation of the number if it has one, unless a
+    /// [custom allocator](crate::allocator) is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ibig::{ubig, UBig};
+    /// let x = (ubig!(1) << UBig::WORD_BITS as usize) + ubig!(5);
+    /// assert_eq!(x.into_limbs(), [5, 1]);
+    /// assert!(ubig!(0).into_limbs().is_empty());
+    /// ```
+    pub fn into_limbs(self) -> Vec<Word> {
+        match self.into_repr() {
+            Small(0) => Vec::new(),
+            Small(word) => vec![word],
+            Large(buffer) => buffer.into_vec(),
+        }
+    }
+
     /// Maximum length in `Word`s.
     ///
     /// Ensures that the number of bits fits in `usize`, which is useful for bit count
@@ -252,6 +335,22 @@ mod tests {
         assert_eq!(capacity(&a), capacity(&num));
     }
 
+    #[test]
+    fn test_limbs_reuse_allocation() {
+        let limbs: Vec<Word> = (1..=10).collect();
+        let ptr = limbs.as_ptr();
+        let num = UBig::from_limbs(limbs);
+        assert_eq!(num.as_limbs().as_ptr(), ptr);
+        let limbs = num.into_limbs();
+        assert_eq!(limbs.as_ptr(), ptr);
+
+        // Too much capacity.
+        let mut limbs = Vec::with_capacity(100);
+        limbs.extend(1..=10);
+        let num = UBig::from_limbs(limbs);
+        assert_eq!(capacity(&num), 13);
+    }
+
     fn gen_ubig(num_words: u16) -> UBig {
         let mut buf = Buffer::allocate(num_words.into());
         for i in 0..num_words {
@@ -3,7 +3,7 @@ use core::{
     fmt::Debug,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, IBig, UBig};
 
 /// Test a + b = c in various ways.
 fn test_add_sub<'a, T>(a: &'a T, b: &'a T, c: &'a T)
@@ -259,4 +259,17 @@ fn test_add_sub_ibig_primitive() {
     x -= -1;
     x -= &10;
     assert_eq!(x, ibig!(-6));
+}
+
+#[test]
+fn test_sum() {
+    let v = [ubig!(1), ubig!(2), ubig!(3)];
+    assert_eq!(v.iter().sum::<UBig>(), ubig!(6));
+    assert_eq!(v.iter().cloned().sum::<UBig>(), ubig!(6));
+    assert_eq!(Vec::<UBig>::new().into_iter().sum::<UBig>(), ubig!(0));
+
+    let v = [ibig!(1), ibig!(-2), ibig!(-3)];
+    assert_eq!(v.iter().sum::<IBig>(), ibig!(-4));
+    assert_eq!(v.iter().cloned().sum::<IBig>(), ibig!(-4));
+    assert_eq!(Vec::<IBig>::new().iter().sum::<IBig>(), ibig!(0));
 }
\ No newline at end of file
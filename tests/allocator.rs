@@ -0,0 +1,73 @@
+use ibig::{
+    allocator, error::SetAllocatorError, ibig, ops::UnsignedAbs, ubig, IBig, Scratch, UBig,
+};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicIsize, AtomicUsize, Ordering},
+};
+
+/// Counts allocations and live bytes.
+struct Counting {
+    allocations: AtomicUsize,
+    live_bytes: AtomicIsize,
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::SeqCst);
+        self.live_bytes
+            .fetch_add(layout.size() as isize, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.live_bytes
+            .fetch_sub(layout.size() as isize, Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+static COUNTING: Counting = Counting {
+    allocations: AtomicUsize::new(0),
+    live_bytes: AtomicIsize::new(0),
+};
+
+// The allocator is global, so everything is in one test.
+#[test]
+fn test_custom_allocator() {
+    // Numbers that fit in a word don't allocate.
+    assert_eq!(UBig::from_limbs(vec![5, 0, 0]), ubig!(5));
+    assert_eq!(UBig::from_limbs(Vec::with_capacity(10)), ubig!(0));
+    assert_eq!(ubig!(5) + ubig!(7), ubig!(12));
+
+    allocator::set_allocator(&COUNTING).unwrap();
+    assert_eq!(allocator::set_allocator(&COUNTING), Err(SetAllocatorError));
+    assert_eq!(
+        SetAllocatorError.to_string(),
+        "allocator already set or in use"
+    );
+
+    {
+        let a = (ubig!(1) << 10000) - ubig!(1);
+        assert!(COUNTING.allocations.load(Ordering::SeqCst) > 0);
+        assert!(COUNTING.live_bytes.load(Ordering::SeqCst) > 0);
+
+        // Multiplication uses temporary memory.
+        let b = &a * &a;
+        assert_eq!(b, (ubig!(1) << 20000) - (ubig!(1) << 10001) + ubig!(1));
+        let c = -ibig!(1) * IBig::from(b.clone());
+        assert_eq!(c.unsigned_abs(), b);
+
+        let mut scratch = Scratch::new();
+        let mut product = UBig::default();
+        UBig::mul_into(&mut product, &a, &a, &mut scratch);
+        assert_eq!(product, b);
+
+        // Limbs are copied between the allocator and the global allocator.
+        let limbs = product.into_limbs();
+        assert_eq!(UBig::from_limbs(limbs), b);
+
+        assert_eq!(b.to_string().len(), 6021);
+    }
+    assert_eq!(COUNTING.live_bytes.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,14 @@
+use ibig::{allocator, error::SetAllocatorError, ubig};
+use std::alloc::System;
+
+static SYSTEM: System = System;
+
+// The allocator is global, so everything is in one test.
+#[test]
+fn test_set_allocator_after_allocation() {
+    let a = ubig!(1) << 1000;
+    assert_eq!(allocator::set_allocator(&SYSTEM), Err(SetAllocatorError));
+    drop(a);
+    // Still rejected after the memory is freed.
+    assert_eq!(allocator::set_allocator(&SYSTEM), Err(SetAllocatorError));
+}
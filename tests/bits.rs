@@ -2,7 +2,7 @@
 use ibig::{
     ibig,
     ops::{AndNot, NextPowerOfTwo},
-    ubig, IBig,
+    ubig, IBig, UBig,
 };
 
 #[test]
@@ -56,6 +56,8 @@ fn test_clear_bit() {
     assert_eq!(a, ubig!(_0x80000000000000000000000000000008));
     a.clear_bit(127);
     assert_eq!(a, ubig!(0b1000));
+    a.clear_bit(1000);
+    assert_eq!(a, ubig!(0b1000));
     a.clear_bit(3);
     assert_eq!(a, ubig!(0));
 }
@@ -106,6 +108,306 @@ fn test_is_power_of_two() {
     );
 }
 
+#[test]
+fn test_toggle_bit() {
+    let mut a = ubig!(0);
+    a.toggle_bit(100);
+    assert_eq!(a, ubig!(1) << 100);
+    a.toggle_bit(3);
+    assert_eq!(a, (ubig!(1) << 100) + ubig!(8));
+    a.toggle_bit(100);
+    assert_eq!(a, ubig!(8));
+    a.toggle_bit(3);
+    assert_eq!(a, ubig!(0));
+}
+
+#[test]
+fn test_count_ones_zeros() {
+    assert_eq!(ubig!(0).count_ones(), 0);
+    assert_eq!(ubig!(0).count_zeros(), 0);
+    assert_eq!(ubig!(0b1011).count_ones(), 3);
+    assert_eq!(ubig!(0b1011).count_zeros(), 1);
+    let a = (ubig!(1) << 1000) - ubig!(1);
+    assert_eq!(a.count_ones(), 1000);
+    assert_eq!(a.count_zeros(), 0);
+    let a = ubig!(1) << 1000;
+    assert_eq!(a.count_ones(), 1);
+    assert_eq!(a.count_zeros(), 1000);
+}
+
+#[test]
+fn test_trailing_ones() {
+    assert_eq!(ubig!(0).trailing_ones(), 0);
+    assert_eq!(ubig!(0b1011).trailing_ones(), 2);
+    assert_eq!(ubig!(0b1010).trailing_ones(), 0);
+    assert_eq!(((ubig!(1) << 1000) - ubig!(1)).trailing_ones(), 1000);
+    assert_eq!(
+        (((ubig!(1) << 1000) - ubig!(1)) ^ (ubig!(1) << 127)).trailing_ones(),
+        127
+    );
+    assert_eq!(((ubig!(5) << 200) - ubig!(1)).trailing_ones(), 200);
+    assert_eq!(((ubig!(1) << 64) - ubig!(1)).trailing_ones(), 64);
+}
+
+#[test]
+fn test_iter_ones() {
+    assert_eq!(ubig!(0).iter_ones().next(), None);
+    assert_eq!(ubig!(0b1011).iter_ones().collect::<Vec<_>>(), [0, 1, 3]);
+    let a = ubig!(1) << 1000 | ubig!(1) << 64 | ubig!(1) << 63 | ubig!(2);
+    let mut iter = a.iter_ones();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.collect::<Vec<_>>(), [63, 64, 1000]);
+
+    let a = ubig!(_0x123456789abcdef0123456789abcdef0fedcba9876543210) << 77;
+    let expected: Vec<usize> = (0..a.bit_len()).filter(|&i| a.bit(i)).collect();
+    assert_eq!(a.iter_ones().collect::<Vec<_>>(), expected);
+    assert_eq!(a.iter_ones().count(), a.count_ones());
+}
+
+#[test]
+fn test_bits_range() {
+    let a = ubig!(_0x123456789abcdef0123456789abcdef0fedcba9876543210);
+    let shifted =
+        |start: usize, end: usize| (&a >> start) & ((ubig!(1) << (end - start)) - ubig!(1));
+    for &(start, end) in &[
+        (0, 0),
+        (0, 1),
+        (3, 7),
+        (0, 64),
+        (1, 65),
+        (60, 70),
+        (63, 130),
+        (64, 128),
+        (5, 190),
+        (100, 300),
+        (191, 192),
+        (192, 1000),
+        (2000, 3000),
+    ] {
+        assert_eq!(
+            a.bits(start..end),
+            shifted(start, end),
+            "{}..{}",
+            start,
+            end
+        );
+        assert_eq!(
+            ubig!(0x1234).bits(start..end),
+            (ubig!(0x1234) >> start) & ((ubig!(1) << (end - start)) - ubig!(1))
+        );
+    }
+    assert_eq!(a.bits(..), a);
+    assert_eq!(a.bits(100..), &a >> 100);
+    assert_eq!(a.bits(..=3), ubig!(0));
+    assert_eq!(a.bits(..=4), ubig!(0x10));
+}
+
+#[test]
+#[should_panic]
+fn test_bits_range_invalid() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let _ = ubig!(5).bits(3..2);
+}
+
+#[test]
+fn test_set_clear_bits_range() {
+    let reference_set = |a: &UBig, start: usize, end: usize| {
+        let mut a = a.clone();
+        for i in start..end {
+            a.set_bit(i);
+        }
+        a
+    };
+    let reference_clear = |a: &UBig, start: usize, end: usize| {
+        let mut a = a.clone();
+        for i in start..end {
+            a.clear_bit(i);
+        }
+        a
+    };
+    for a in &[
+        ubig!(0),
+        ubig!(0b1001),
+        ubig!(_0x123456789abcdef0123456789abcdef0fedcba9876543210),
+    ] {
+        for &(start, end) in &[
+            (0, 0),
+            (0, 1),
+            (3, 7),
+            (0, 64),
+            (1, 65),
+            (60, 70),
+            (63, 130),
+            (64, 128),
+            (5, 190),
+            (100, 300),
+            (2000, 3000),
+        ] {
+            let mut b = a.clone();
+            b.set_bits_range(start..end);
+            assert_eq!(b, reference_set(a, start, end));
+            let mut b = a.clone();
+            b.clear_bits_range(start..end);
+            assert_eq!(
+                b,
+                reference_clear(a, start, end),
+                "{} {}..{}",
+                a,
+                start,
+                end
+            );
+        }
+    }
+    let mut a = ubig!(0);
+    a.set_bits_range(..=127);
+    assert_eq!(a, (ubig!(1) << 128) - ubig!(1));
+    a.clear_bits_range(64..);
+    assert_eq!(a, (ubig!(1) << 64) - ubig!(1));
+    a.clear_bits_range(..);
+    assert_eq!(a, ubig!(0));
+}
+
+#[test]
+#[should_panic]
+fn test_set_bits_range_unbounded() {
+    ubig!(5).set_bits_range(3..);
+}
+
+#[test]
+fn test_ibig_bits_i64() {
+    let values: Vec<i64> = (-70..70)
+        .chain(
+            [i64::MIN, i64::MIN + 1, i64::MAX, -(1 << 40), (1 << 40) - 1]
+                .iter()
+                .copied(),
+        )
+        .collect();
+    for &x in &values {
+        let a = IBig::from(x);
+        for n in 0..100 {
+            let expected = if n < 64 { x >> n & 1 != 0 } else { x < 0 };
+            assert_eq!(a.bit(n), expected, "{} {}", x, n);
+
+            let power = ibig!(1) << n;
+            let mut b = a.clone();
+            b.set_bit(n);
+            assert_eq!(b, if expected { a.clone() } else { &a + &power });
+            let mut b = a.clone();
+            b.clear_bit(n);
+            assert_eq!(b, if expected { &a - &power } else { a.clone() });
+
+            let mut b = a.clone();
+            b.toggle_bit(n);
+            b.toggle_bit(n);
+            assert_eq!(b, a);
+        }
+        if x >= 0 {
+            assert_eq!(a.count_ones(), Some(x.count_ones() as usize));
+            assert_eq!(a.count_zeros(), None);
+        } else {
+            assert_eq!(a.count_ones(), None);
+            assert_eq!(a.count_zeros(), Some(x.count_zeros() as usize));
+        }
+        assert_eq!(
+            a.bit_len(),
+            128 - i128::from(x).abs().leading_zeros() as usize
+        );
+    }
+}
+
+#[test]
+fn test_ibig_bits_large() {
+    let a = -(ibig!(1) << 200);
+    assert!(!a.bit(199));
+    assert!(a.bit(200));
+    assert!(a.bit(1000));
+    assert_eq!(a.count_zeros(), Some(200));
+    assert_eq!(a.bit_len(), 201);
+
+    let mut b = a.clone();
+    b.set_bit(0);
+    assert_eq!(b, -(ibig!(1) << 200) + ibig!(1));
+    b.clear_bit(200);
+    assert_eq!(b, -(ibig!(1) << 201) + ibig!(1));
+    b.clear_bit(0);
+    b.set_bit(200);
+    assert_eq!(b, a);
+    b.clear_bit(1000);
+    assert_eq!(b, a - (ibig!(1) << 1000));
+
+    // Carries and borrows across words.
+    let values = [
+        -(ibig!(1) << 200),
+        -(ibig!(5) << 130),
+        -(((ibig!(1) << 192) - ibig!(1)) << 64),
+        -((ibig!(1) << 256) - ibig!(1)),
+    ];
+    for a in &values {
+        for n in 0..300 {
+            let expected = (a >> n) & ibig!(1) == ibig!(1);
+            assert_eq!(a.bit(n), expected, "{} {}", a, n);
+
+            let power = ibig!(1) << n;
+            let mut b = a.clone();
+            b.set_bit(n);
+            assert_eq!(b, if expected { a.clone() } else { a + &power });
+            let mut b = a.clone();
+            b.clear_bit(n);
+            assert_eq!(b, if expected { a - &power } else { a.clone() });
+        }
+    }
+}
+
+#[test]
+fn test_hamming_distance() {
+    assert_eq!(ubig!(0).hamming_distance(&ubig!(0)), 0);
+    assert_eq!(ubig!(0b1011).hamming_distance(&ubig!(0b1011)), 0);
+    assert_eq!(ubig!(0b1011).hamming_distance(&ubig!(0b110)), 3);
+    let a = ubig!(_0x123456789abcdef0123456789abcdef0fedcba9876543210);
+    let b = (ubig!(1) << 500) - ubig!(1);
+    assert_eq!(a.hamming_distance(&b), (&a ^ &b).count_ones());
+    assert_eq!(b.hamming_distance(&a), (&a ^ &b).count_ones());
+    assert_eq!(a.hamming_distance(&ubig!(5)), (&a ^ ubig!(5)).count_ones());
+}
+
+#[test]
+#[allow(clippy::bool_assert_comparison)]
+fn test_parity() {
+    assert_eq!(ubig!(0).parity(), false);
+    assert_eq!(ubig!(1).parity(), true);
+    assert_eq!(ubig!(0b1011).parity(), true);
+    assert_eq!(((ubig!(1) << 1000) - ubig!(1)).parity(), false);
+    assert_eq!(((ubig!(1) << 1001) - ubig!(1)).parity(), true);
+    assert_eq!(((ubig!(1) << 1000) + ubig!(1)).parity(), false);
+}
+
+#[test]
+fn test_scan() {
+    let a = (ubig!(0b1011) << 200) | ubig!(0b110) | ((ubig!(1) << 128) - (ubig!(1) << 60));
+    for from in 0..300 {
+        let next1 = (from..a.bit_len()).find(|&i| a.bit(i));
+        assert_eq!(a.scan1(from), next1, "{}", from);
+        let next0 = (from..).find(|&i| !a.bit(i)).unwrap();
+        assert_eq!(a.scan0(from), next0, "{}", from);
+    }
+    assert_eq!(ubig!(0).scan1(0), None);
+    assert_eq!(ubig!(0).scan0(5), 5);
+    let ones = (ubig!(1) << 128) - ubig!(1);
+    assert_eq!(ones.scan0(0), 128);
+    assert_eq!(ones.scan1(127), Some(127));
+    assert_eq!(ones.scan1(128), None);
+}
+
+#[test]
+fn test_leading_zeros_in() {
+    assert_eq!(ubig!(0).leading_zeros_in(0), Some(0));
+    assert_eq!(ubig!(1).leading_zeros_in(0), None);
+    assert_eq!(ubig!(0b101).leading_zeros_in(3), Some(0));
+    assert_eq!(ubig!(0b101).leading_zeros_in(100), Some(97));
+    assert_eq!((ubig!(1) << 200).leading_zeros_in(256), Some(55));
+    assert_eq!((ubig!(1) << 200).leading_zeros_in(200), None);
+}
+
 #[test]
 fn test_next_power_of_two() {
     assert_eq!(ubig!(0).next_power_of_two(), ubig!(1));
@@ -0,0 +1,126 @@
+use ibig::{ubig, UBig};
+
+fn naive_factorial(n: usize) -> UBig {
+    let mut result = ubig!(1);
+    for i in 2..=n {
+        result *= UBig::from(i);
+    }
+    result
+}
+
+#[test]
+fn test_factorial() {
+    assert_eq!(UBig::factorial(0), ubig!(1));
+    assert_eq!(UBig::factorial(1), ubig!(1));
+    assert_eq!(UBig::factorial(20), ubig!(2432902008176640000));
+    assert_eq!(
+        UBig::factorial(30),
+        ubig!(265252859812191058636308480000000)
+    );
+    for &n in &[255, 256, 257, 1000, 3001] {
+        assert_eq!(UBig::factorial(n), naive_factorial(n));
+    }
+}
+
+#[test]
+fn test_double_factorial() {
+    let naive = |n: usize| {
+        let mut result = ubig!(1);
+        let mut i = n;
+        while i > 1 {
+            result *= UBig::from(i);
+            i -= 2;
+        }
+        result
+    };
+    assert_eq!(UBig::double_factorial(0), ubig!(1));
+    assert_eq!(UBig::double_factorial(1), ubig!(1));
+    assert_eq!(UBig::double_factorial(2), ubig!(2));
+    assert_eq!(UBig::double_factorial(9), ubig!(945));
+    assert_eq!(UBig::double_factorial(10), ubig!(3840));
+    for &n in &[255, 256, 257, 258, 1000, 1001, 3001] {
+        assert_eq!(UBig::double_factorial(n), naive(n));
+    }
+}
+
+#[test]
+fn test_binomial() {
+    assert_eq!(UBig::binomial(0, 0), ubig!(1));
+    assert_eq!(UBig::binomial(0, 1), ubig!(0));
+    assert_eq!(UBig::binomial(10, 0), ubig!(1));
+    assert_eq!(UBig::binomial(10, 10), ubig!(1));
+    assert_eq!(UBig::binomial(10, 3), ubig!(120));
+    assert_eq!(UBig::binomial(10, 7), ubig!(120));
+    assert_eq!(
+        UBig::binomial(100, 50),
+        ubig!(100891344545564193334812497256)
+    );
+
+    // Pascal's triangle.
+    let mut row = vec![ubig!(1)];
+    for n in 1..=100 {
+        let mut next = vec![ubig!(1)];
+        for k in 1..n {
+            next.push(&row[k - 1] + &row[k]);
+        }
+        next.push(ubig!(1));
+        row = next;
+        for (k, x) in row.iter().enumerate() {
+            assert_eq!(UBig::binomial(n, k), *x);
+        }
+    }
+
+    for &(n, k) in &[(5000, 2500), (5000, 100), (3000, 1234)] {
+        assert_eq!(
+            UBig::binomial(n, k),
+            naive_factorial(n) / naive_factorial(k) / naive_factorial(n - k)
+        );
+    }
+    assert_eq!(
+        UBig::binomial(100000, 3),
+        ubig!(100000) * ubig!(99999) * ubig!(99998) / ubig!(6)
+    );
+    assert_eq!(
+        UBig::binomial(usize::MAX, 2),
+        UBig::from(usize::MAX) * UBig::from(usize::MAX - 1) / ubig!(2)
+    );
+}
+
+#[test]
+fn test_multinomial() {
+    assert_eq!(UBig::multinomial(&[]), ubig!(1));
+    assert_eq!(UBig::multinomial(&[5]), ubig!(1));
+    assert_eq!(UBig::multinomial(&[0, 0]), ubig!(1));
+    assert_eq!(UBig::multinomial(&[3, 2]), ubig!(10));
+    assert_eq!(UBig::multinomial(&[1, 1, 1, 1]), ubig!(24));
+    // MISSISSIPPI
+    assert_eq!(UBig::multinomial(&[1, 4, 4, 2]), ubig!(34650));
+    let ks = [300, 1, 200, 0, 500];
+    assert_eq!(
+        UBig::multinomial(&ks),
+        naive_factorial(1001) / naive_factorial(300) / naive_factorial(200) / naive_factorial(500)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_multinomial_overflow() {
+    let _ = UBig::multinomial(&[usize::MAX, 1]);
+}
+
+#[test]
+fn test_primorial() {
+    assert_eq!(UBig::primorial(0), ubig!(1));
+    assert_eq!(UBig::primorial(1), ubig!(1));
+    assert_eq!(UBig::primorial(2), ubig!(2));
+    assert_eq!(UBig::primorial(30), ubig!(6469693230));
+    assert_eq!(UBig::primorial(31), ubig!(200560490130));
+    let n = 2000;
+    let mut expected = ubig!(1);
+    for i in 2..=n {
+        if (2..i).take_while(|d| d * d <= i).all(|d| i % d != 0) {
+            expected *= UBig::from(i);
+        }
+    }
+    assert_eq!(UBig::primorial(n), expected);
+}
@@ -1,5 +1,5 @@
 
-use ibig::{error::OutOfBoundsError, ibig, ubig, IBig, UBig};
+use ibig::{error::OutOfBoundsError, ibig, ubig, IBig, UBig, Word};
 use std::convert::TryFrom;
 
 #[test]
@@ -24,6 +24,181 @@ fn test_from_to_be_bytes() {
     assert_eq!(UBig::from_be_bytes(&bytes).to_be_bytes(), bytes);
 }
 
+#[test]
+fn test_byte_len() {
+    assert_eq!(ubig!(0).byte_len(), 0);
+    assert_eq!(ubig!(1).byte_len(), 1);
+    assert_eq!(ubig!(0xffff).byte_len(), 2);
+    assert_eq!(ubig!(0x10000).byte_len(), 3);
+    for &bytes in &[1, 7, 8, 9, 16, 17, 100] {
+        let x = (ubig!(1) << (8 * bytes)) - ubig!(1);
+        assert_eq!(x.byte_len(), bytes);
+        assert_eq!(x.byte_len(), x.to_le_bytes().len());
+    }
+}
+
+#[test]
+fn test_write_bytes_padded() {
+    let bytes: Vec<u8> = (1..=17).collect();
+    let x = UBig::from_le_bytes(&bytes);
+    for len in 17..30 {
+        let mut buffer = vec![0xff; len];
+        x.write_le_bytes_padded(&mut buffer).unwrap();
+        assert_eq!(buffer[..17], bytes[..]);
+        assert!(buffer[17..].iter().all(|&b| b == 0));
+
+        let mut buffer = vec![0xff; len];
+        x.write_be_bytes_padded(&mut buffer).unwrap();
+        assert_eq!(UBig::from_be_bytes(&buffer), x);
+        assert_eq!(buffer[len - 17..], x.to_be_bytes()[..]);
+        assert!(buffer[..len - 17].iter().all(|&b| b == 0));
+    }
+    let mut buffer = [0xff; 16];
+    assert_eq!(x.write_le_bytes_padded(&mut buffer), Err(OutOfBoundsError));
+    assert_eq!(x.write_be_bytes_padded(&mut buffer), Err(OutOfBoundsError));
+
+    let mut buffer = [0xff; 3];
+    ubig!(0).write_le_bytes_padded(&mut buffer).unwrap();
+    assert_eq!(buffer, [0, 0, 0]);
+    ubig!(0).write_be_bytes_padded(&mut []).unwrap();
+    ubig!(0x0102).write_be_bytes_padded(&mut buffer).unwrap();
+    assert_eq!(buffer, [0, 1, 2]);
+}
+
+#[test]
+fn test_u64_slice() {
+    assert!(ubig!(0).as_u64_slice().is_empty());
+    assert_eq!(*ubig!(5).as_u64_slice(), [5]);
+    let x = ubig!(0x123456789abcdef00fedcba987654321);
+    assert_eq!(*x.as_u64_slice(), [0x0fedcba987654321, 0x123456789abcdef0]);
+    assert_eq!(UBig::from_u64_slice(&x.as_u64_slice()), x);
+    assert_eq!(UBig::from_u64_slice(&[]), ubig!(0));
+    assert_eq!(UBig::from_u64_slice(&[0, 0, 0]), ubig!(0));
+    assert_eq!(UBig::from_u64_slice(&[7, 0]), ubig!(7));
+    assert_eq!(
+        UBig::from_u64_slice(&[1, 2, 3]),
+        (ubig!(3) << 128) + (ubig!(2) << 64) + ubig!(1)
+    );
+}
+
+#[test]
+fn test_limbs() {
+    let word_bits = UBig::WORD_BITS as usize;
+    assert!(ubig!(0).as_limbs().is_empty());
+    assert_eq!(ubig!(0).limbs().len(), 0);
+    assert!(ubig!(0).into_limbs().is_empty());
+    assert_eq!(ubig!(7).as_limbs(), [7]);
+    assert_eq!(ubig!(7).into_limbs(), [7]);
+
+    let x = (ubig!(3) << (2 * word_bits)) + (ubig!(2) << word_bits) + ubig!(1);
+    assert_eq!(x.as_limbs(), [1, 2, 3]);
+    assert_eq!(x.limbs().collect::<Vec<Word>>(), [1, 2, 3]);
+    assert_eq!(x.limbs().rev().collect::<Vec<Word>>(), [3, 2, 1]);
+    assert_eq!(x.limbs().len(), 3);
+    assert_eq!(UBig::from_limbs(x.clone().into_limbs()), x);
+    assert_eq!(UBig::from_limbs(vec![1, 2, 3, 0, 0]), x);
+
+    assert_eq!(UBig::from_limbs(vec![]), ubig!(0));
+    assert_eq!(UBig::from_limbs(vec![0, 0]), ubig!(0));
+    assert_eq!(UBig::from_limbs(vec![9, 0]), ubig!(9));
+    assert_eq!(UBig::from_limbs(vec![Word::MAX]), UBig::from(Word::MAX));
+}
+
+/// Minimal little-endian two's complement bytes of a primitive.
+fn signed_le_bytes_i128(x: i128) -> Vec<u8> {
+    let mut bytes = x.to_le_bytes().to_vec();
+    while bytes.len() > 1 && (bytes[bytes.len() - 2] as i8 >> 7) as u8 == bytes[bytes.len() - 1] {
+        bytes.pop();
+    }
+    bytes
+}
+
+#[test]
+fn test_signed_le_bytes() {
+    for &x in &[
+        0,
+        1,
+        -1,
+        127,
+        128,
+        -128,
+        -129,
+        255,
+        256,
+        -256,
+        -257,
+        0x7fffffff,
+        -0x80000000,
+        0x123456789abcdef0,
+        -0x123456789abcdef0,
+        i128::MAX,
+        i128::MIN,
+        i128::MIN + 1,
+    ] {
+        let bytes = signed_le_bytes_i128(x);
+        assert_eq!(IBig::from(x).to_signed_le_bytes(), bytes);
+        assert_eq!(IBig::from_signed_le_bytes(&bytes), IBig::from(x));
+        assert_eq!(
+            IBig::from(x).to_signed_le_bytes_fixed(16),
+            Ok(x.to_le_bytes().to_vec())
+        );
+        assert_eq!(IBig::from_signed_le_bytes(&x.to_le_bytes()), IBig::from(x));
+    }
+
+    assert_eq!(IBig::from_signed_le_bytes(&[]), ibig!(0));
+    assert_eq!(IBig::from_signed_le_bytes(&[0xff; 100]), ibig!(-1));
+    assert_eq!(IBig::from_signed_le_bytes(&[0; 100]), ibig!(0));
+    let x = ibig!(-1) << 200;
+    let bytes = x.to_signed_le_bytes();
+    assert_eq!(bytes.len(), 26);
+    assert_eq!(bytes[25], 0xff);
+    assert_eq!(IBig::from_signed_le_bytes(&bytes), x);
+    let x = (ibig!(1) << 207) - ibig!(1);
+    assert_eq!(x.to_signed_le_bytes().len(), 26);
+    assert_eq!((&x + ibig!(1)).to_signed_le_bytes().len(), 27);
+    assert_eq!((-&x - ibig!(1)).to_signed_le_bytes().len(), 26);
+    assert_eq!((-&x - ibig!(2)).to_signed_le_bytes().len(), 27);
+
+    assert_eq!(ibig!(0).to_signed_le_bytes_fixed(0), Ok(vec![]));
+    assert_eq!(ibig!(1).to_signed_le_bytes_fixed(0), Err(OutOfBoundsError));
+    assert_eq!(ibig!(127).to_signed_le_bytes_fixed(1), Ok(vec![0x7f]));
+    assert_eq!(
+        ibig!(128).to_signed_le_bytes_fixed(1),
+        Err(OutOfBoundsError)
+    );
+    assert_eq!(ibig!(-128).to_signed_le_bytes_fixed(1), Ok(vec![0x80]));
+    assert_eq!(
+        ibig!(-129).to_signed_le_bytes_fixed(1),
+        Err(OutOfBoundsError)
+    );
+}
+
+#[test]
+fn test_signed_be_bytes() {
+    for &x in &[0, 1, -1, 128, -128, -129, 0x123456789abcdef0, i128::MIN] {
+        let mut bytes = signed_le_bytes_i128(x);
+        bytes.reverse();
+        assert_eq!(IBig::from(x).to_signed_be_bytes(), bytes);
+        assert_eq!(IBig::from_signed_be_bytes(&bytes), IBig::from(x));
+        assert_eq!(
+            IBig::from(x).to_signed_be_bytes_fixed(16),
+            Ok(x.to_be_bytes().to_vec())
+        );
+        assert_eq!(IBig::from_signed_be_bytes(&x.to_be_bytes()), IBig::from(x));
+    }
+
+    assert_eq!(IBig::from_signed_be_bytes(&[]), ibig!(0));
+    assert_eq!(IBig::from_signed_be_bytes(&[0xff; 100]), ibig!(-1));
+    assert_eq!(
+        ibig!(-2).to_signed_be_bytes_fixed(3),
+        Ok(vec![0xff, 0xff, 0xfe])
+    );
+    assert_eq!(
+        ibig!(-129).to_signed_be_bytes_fixed(1),
+        Err(OutOfBoundsError)
+    );
+}
+
 #[test]
 fn test_ubig_from_unsigned() {
     assert_eq!(UBig::from(0xf1u8), UBig::from_be_bytes(&[0xf1]));
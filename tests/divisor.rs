@@ -0,0 +1,111 @@
+use ibig::{ubig, Divisor, UBig};
+
+fn check(divisor: &UBig, numbers: &[UBig]) {
+    let prepared = Divisor::new(divisor);
+    assert_eq!(prepared.divisor(), *divisor);
+    for x in numbers {
+        let q = x / divisor;
+        let r = x % divisor;
+        assert_eq!(prepared.div_rem(x), (q.clone(), r.clone()));
+        assert_eq!(prepared.div(x), q);
+        assert_eq!(prepared.rem(x), r);
+    }
+}
+
+#[test]
+fn test_divisor_from_word() {
+    let numbers = [
+        ubig!(0),
+        ubig!(6),
+        ubig!(1000),
+        ubig!(_0xffffffffffffffffffffffffffffffff),
+        ubig!(10).pow(100),
+    ];
+    for &divisor in &[1, 7, 1000, 0xffff] {
+        let prepared = Divisor::from_word(divisor);
+        assert_eq!(prepared.divisor(), UBig::from(divisor));
+        for x in &numbers {
+            let r = x % UBig::from(divisor);
+            assert_eq!(prepared.rem(x), r);
+            assert_eq!(UBig::from(prepared.rem_word(x)), r);
+        }
+    }
+}
+
+#[test]
+fn test_divisor_small() {
+    let numbers = [
+        ubig!(0),
+        ubig!(1),
+        ubig!(6),
+        ubig!(7),
+        ubig!(1000),
+        ubig!(_0xffffffffffffffffffffffffffffffff),
+        ubig!(10).pow(100),
+    ];
+    for divisor in &[ubig!(1), ubig!(2), ubig!(7), ubig!(1000), ubig!(0xffff)] {
+        check(divisor, &numbers);
+    }
+}
+
+#[test]
+fn test_divisor_large() {
+    let numbers = [
+        ubig!(0),
+        ubig!(17),
+        ubig!(10).pow(50),
+        ubig!(10).pow(50) - ubig!(1),
+        ubig!(10).pow(100) + ubig!(3),
+        ubig!(3).pow(1000),
+        ubig!(3).pow(10000),
+    ];
+    for divisor in &[
+        ubig!(10).pow(50),
+        ubig!(_0x10000000000000000000000000000000),
+        ubig!(7).pow(300) + ubig!(1),
+        ubig!(10).pow(1000),
+    ] {
+        check(divisor, &numbers);
+    }
+}
+
+#[test]
+fn test_divisor_huge() {
+    // Large enough to use a precomputed reciprocal of the divisor.
+    let bits = 400000;
+    if 3 * bits > UBig::MAX_BIT_LEN {
+        return;
+    }
+    let divisor = ubig!(3).pow(bits / 2) + ubig!(5).pow(bits / 3);
+    let numbers = [
+        ubig!(5),
+        &divisor - ubig!(1),
+        divisor.clone(),
+        ubig!(7).pow(bits / 2),
+        ubig!(7).pow(bits) + ubig!(1),
+    ];
+    check(&divisor, &numbers);
+}
+
+#[test]
+fn test_divisor_debug() {
+    assert_eq!(format!("{:?}", Divisor::new(&ubig!(17))), "Divisor(17)");
+}
+
+#[test]
+#[should_panic]
+fn test_divisor_zero() {
+    let _ = Divisor::new(&ubig!(0));
+}
+
+#[test]
+#[should_panic]
+fn test_divisor_from_word_zero() {
+    let _ = Divisor::from_word(0);
+}
+
+#[test]
+#[should_panic]
+fn test_divisor_rem_word_large() {
+    let _ = Divisor::new(&ubig!(10).pow(50)).rem_word(&ubig!(7));
+}
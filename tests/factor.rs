@@ -0,0 +1,180 @@
+use ibig::{factor, ubig, UBig};
+use std::convert::TryFrom;
+
+fn is_prime_naive(n: u32) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+fn mersenne(exponent: usize) -> UBig {
+    (ubig!(1) << exponent) - ubig!(1)
+}
+
+#[test]
+fn test_is_probable_prime() {
+    for n in 0..10000 {
+        assert_eq!(
+            factor::is_probable_prime(&UBig::from(n)),
+            is_prime_naive(n),
+            "{}",
+            n
+        );
+    }
+    // Strong pseudoprimes to base 2, Lucas pseudoprimes, Carmichael numbers.
+    for &n in &[
+        2047u64,
+        3277,
+        4033,
+        5459,
+        5777,
+        10877,
+        561,
+        1105,
+        3215031751,
+        2152302898747,
+        3474749660383,
+        341550071728321,
+    ] {
+        assert!(!factor::is_probable_prime(&UBig::from(n)), "{}", n);
+    }
+    for &e in &[61, 89, 107, 127, 521] {
+        assert!(factor::is_probable_prime(&mersenne(e)));
+    }
+    for &e in &[67, 101, 128, 257] {
+        assert!(!factor::is_probable_prime(&mersenne(e)));
+    }
+    assert!(!factor::is_probable_prime(&(mersenne(61) * mersenne(89))));
+    assert!(!factor::is_probable_prime(&mersenne(61).pow(2)));
+}
+
+#[test]
+fn test_trial_division() {
+    let (factors, cofactor) = factor::trial_division(&ubig!(1001000), 10);
+    assert_eq!(
+        factors.into_iter().collect::<Vec<_>>(),
+        [(ubig!(2), 3), (ubig!(5), 3), (ubig!(7), 1)]
+    );
+    assert_eq!(cofactor, ubig!(143));
+
+    let (factors, cofactor) = factor::trial_division(&ubig!(606), 100);
+    assert_eq!(
+        factors.into_iter().collect::<Vec<_>>(),
+        [(ubig!(2), 1), (ubig!(3), 1), (ubig!(101), 1)]
+    );
+    assert_eq!(cofactor, ubig!(1));
+
+    let (factors, cofactor) = factor::trial_division(&ubig!(1001), 1);
+    assert!(factors.is_empty());
+    assert_eq!(cofactor, ubig!(1001));
+
+    let (factors, cofactor) = factor::trial_division(&ubig!(1), 1000);
+    assert!(factors.is_empty());
+    assert_eq!(cofactor, ubig!(1));
+
+    let n = ubig!(3).pow(50) * ubig!(9973).pow(3) * mersenne(61);
+    let (factors, cofactor) = factor::trial_division(&n, 10000);
+    assert_eq!(
+        factors.into_iter().collect::<Vec<_>>(),
+        [(ubig!(3), 50), (ubig!(9973), 3)]
+    );
+    assert_eq!(cofactor, mersenne(61));
+}
+
+#[test]
+#[should_panic]
+fn test_trial_division_zero() {
+    let _ = factor::trial_division(&ubig!(0), 100);
+}
+
+#[test]
+fn test_pollard_rho() {
+    let d = factor::pollard_rho(&mersenne(67), 100000).unwrap();
+    assert!(d == ubig!(193707721) || d == ubig!(761838257287));
+    let n = (ubig!(1) << 64) + ubig!(1);
+    let d = factor::pollard_rho(&n, 100000).unwrap();
+    assert!(d == ubig!(274177) || d == ubig!(67280421310721));
+    assert_eq!(factor::pollard_rho(&ubig!(1000), 100), Some(ubig!(2)));
+    assert_eq!(factor::pollard_rho(&mersenne(61), 1000), None);
+    assert_eq!(factor::pollard_rho(&ubig!(1), 1000), None);
+}
+
+#[test]
+fn test_pollard_p_minus_1() {
+    // p - 1 = 2^3 * 3^2 * 97 * 26209 * 32779 * 166667
+    let p = ubig!(1000000000000000009);
+    // q - 1 = 2 * 3 * 17 * 131 * 1427 * 52445056723
+    let q = ubig!(1000000000000000003);
+    let n = &p * &q;
+    assert_eq!(factor::pollard_p_minus_1(&n, 40000), Some(p));
+    assert_eq!(factor::pollard_p_minus_1(&n, 1000), None);
+    assert_eq!(factor::pollard_p_minus_1(&ubig!(1000), 100), Some(ubig!(2)));
+    assert_eq!(factor::pollard_p_minus_1(&mersenne(61), 1000), None);
+}
+
+#[test]
+fn test_ecm() {
+    let p = ubig!(593656751);
+    let q = ubig!(727493966063);
+    let d = factor::ecm(&(&p * &q), 2000, 30).unwrap();
+    assert!(d == p || d == q);
+    assert_eq!(factor::ecm(&ubig!(1000), 100, 1), Some(ubig!(2)));
+    assert_eq!(factor::ecm(&mersenne(61), 1000, 3), None);
+}
+
+#[test]
+fn test_factorize() {
+    assert!(factor::factorize(&ubig!(1)).is_empty());
+    for n in 1..2000u32 {
+        let factors = factor::factorize(&UBig::from(n));
+        let mut product = ubig!(1);
+        for (p, e) in factors {
+            assert!(is_prime_naive(u32::try_from(&p).unwrap()));
+            product *= p.pow(e);
+        }
+        assert_eq!(product, UBig::from(n));
+    }
+
+    let test_cases = [
+        (ubig!(1) << 100, vec![(ubig!(2), 100)]),
+        (
+            ubig!(12345678901234567890),
+            vec![
+                (ubig!(2), 1),
+                (ubig!(3), 2),
+                (ubig!(5), 1),
+                (ubig!(101), 1),
+                (ubig!(3541), 1),
+                (ubig!(3607), 1),
+                (ubig!(3803), 1),
+                (ubig!(27961), 1),
+            ],
+        ),
+        (
+            (ubig!(1) << 64) + ubig!(1),
+            vec![(ubig!(274177), 1), (ubig!(67280421310721), 1)],
+        ),
+        (
+            ubig!(1582729).pow(3) * ubig!(727493966063),
+            vec![(ubig!(1582729), 3), (ubig!(727493966063), 1)],
+        ),
+        (
+            mersenne(61).pow(2) * mersenne(89) * ubig!(4099),
+            vec![(ubig!(4099), 1), (mersenne(61), 2), (mersenne(89), 1)],
+        ),
+        (
+            ubig!(593656751) * ubig!(727493966063),
+            vec![(ubig!(593656751), 1), (ubig!(727493966063), 1)],
+        ),
+    ];
+    for (n, factors) in &test_cases {
+        assert_eq!(
+            factor::factorize(n).into_iter().collect::<Vec<_>>(),
+            *factors
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_factorize_zero() {
+    let _ = factor::factorize(&ubig!(0));
+}
@@ -98,6 +98,67 @@ fn test_gcd_ubig() {
     }
 }
 
+/// Check gcd and extended_gcd without knowing the answer in advance.
+fn check_gcd_large(a: &UBig, b: &UBig) -> UBig {
+    let (g, x, y) = a.extended_gcd(b);
+    assert_eq!(a % &g, ubig!(0));
+    assert_eq!(b % &g, ubig!(0));
+    // Any common divisor of a and b divides x * a + y * b, so g is the greatest.
+    assert_eq!(&x * IBig::from(a) + &y * IBig::from(b), IBig::from(&g));
+    assert!(x.unsigned_abs() <= *b.max(&ubig!(1)));
+    assert!(y.unsigned_abs() <= *a.max(&ubig!(1)));
+    assert_eq!(a.gcd(b), g);
+    g
+}
+
+#[test]
+fn test_gcd_ubig_large() {
+    for &(n, m) in &[
+        (300, 200),
+        (1000, 999),
+        (4096, 3000),
+        (30000, 20000),
+        (30000, 100),
+    ] {
+        let a = (ubig!(1) << n) - ubig!(1);
+        let b = (ubig!(1) << m) - ubig!(1);
+        let (mut x, mut y) = (n, m);
+        while y != 0 {
+            let r = x % y;
+            x = y;
+            y = r;
+        }
+        let expected = (ubig!(1) << x) - ubig!(1);
+        assert_eq!(check_gcd_large(&a, &b), expected);
+        assert_eq!(check_gcd_large(&b, &a), expected);
+    }
+
+    // Consecutive Fibonacci numbers: all quotients are 1.
+    let mut fib = (ubig!(0), ubig!(1));
+    for i in 1..=30000 {
+        fib = (fib.1.clone(), fib.0 + fib.1);
+        if i == 1000 || i == 10000 || i == 30000 {
+            assert_eq!(check_gcd_large(&fib.1, &fib.0), ubig!(1));
+        }
+    }
+
+    // Large common factors and large quotients.
+    for &(k, m) in &[(100, 150), (1000, 1500), (5000, 4000), (20000, 25000)] {
+        // c has fewer than 2k + 6m bits.
+        if 2 * k + 6 * m > UBig::MAX_BIT_LEN {
+            continue;
+        }
+        let g = ubig!(3).pow(k) + ubig!(1);
+        let a = &g * (ubig!(5).pow(m) + ubig!(2));
+        let b = &g * (ubig!(7).pow(k) + ubig!(4));
+        let c = &a * ubig!(10).pow(m) + &b;
+        for (x, y) in &[(&a, &b), (&a, &c), (&b, &c)] {
+            let gcd = check_gcd_large(x, y);
+            assert_eq!(&gcd % &g, ubig!(0));
+        }
+    }
+}
+
 #[test]
 #[should_panic]
 fn test_gcd_ubig_0_0() {
@@ -143,4 +204,77 @@ fn test_gcd_ibig_0_0() {
 #[should_panic]
 fn test_extended_gcd_ibig_0_0() {
     let _ = ibig!(0).extended_gcd(&ibig!(0));
+}
+
+#[test]
+fn test_gcd_cofactors_ubig() {
+    let cases = [
+        (ubig!(0), ubig!(5)),
+        (ubig!(12), ubig!(18)),
+        (ubig!(7), ubig!(1)),
+        (
+            ubig!(_0x123456789123456789123456789123456789),
+            ubig!(_0x987654321987654321987654321987654321),
+        ),
+        (ubig!(3).pow(3000) * ubig!(10), ubig!(6).pow(2500)),
+        (ubig!(5).pow(1000) * ubig!(7).pow(900), ubig!(35).pow(950)),
+    ];
+    for (a, b) in &cases {
+        for (a, b) in [(a, b), (b, a)] {
+            let (g, a_cofactor, b_cofactor) = a.gcd_cofactors(b);
+            assert_eq!(g, a.gcd(b));
+            assert_eq!(&g * &a_cofactor, *a);
+            assert_eq!(&g * &b_cofactor, *b);
+        }
+    }
+
+    let (g, a, b) = ibig!(-12).gcd_cofactors(&ibig!(18));
+    assert_eq!((g, a, b), (ibig!(6), ibig!(-2), ibig!(3)));
+    let (g, a, b) = ibig!(0).gcd_cofactors(&ibig!(-18));
+    assert_eq!((g, a, b), (ibig!(18), ibig!(0), ibig!(-1)));
+}
+
+#[test]
+#[should_panic]
+fn test_gcd_cofactors_0_0() {
+    let _ = ubig!(0).gcd_cofactors(&ubig!(0));
+}
+
+#[test]
+fn test_lcm() {
+    assert_eq!(ubig!(0).lcm(&ubig!(0)), ubig!(0));
+    assert_eq!(ubig!(0).lcm(&ubig!(5)), ubig!(0));
+    assert_eq!(ubig!(4).lcm(&ubig!(6)), ubig!(12));
+    assert_eq!(ubig!(7).lcm(&ubig!(7)), ubig!(7));
+    let a = ubig!(3).pow(1000) * ubig!(2).pow(100);
+    let b = ubig!(3).pow(500) * ubig!(5).pow(700);
+    assert_eq!(
+        a.lcm(&b),
+        ubig!(3).pow(1000) * ubig!(2).pow(100) * ubig!(5).pow(700)
+    );
+
+    assert_eq!(ibig!(-4).lcm(&ibig!(6)), ibig!(12));
+    assert_eq!(ibig!(-4).lcm(&ibig!(-6)), ibig!(12));
+    assert_eq!(ibig!(0).lcm(&ibig!(-6)), ibig!(0));
+}
+
+#[test]
+fn test_gcd_lcm_all() {
+    assert_eq!(UBig::gcd_all(&[]), ubig!(0));
+    assert_eq!(UBig::gcd_all(&[ubig!(0), ubig!(0)]), ubig!(0));
+    assert_eq!(UBig::gcd_all(&[ubig!(0), ubig!(10)]), ubig!(10));
+    assert_eq!(UBig::gcd_all(&[ubig!(12), ubig!(18), ubig!(8)]), ubig!(2));
+    assert_eq!(
+        UBig::gcd_all([ubig!(5), ubig!(7), ubig!(10)].iter()),
+        ubig!(1)
+    );
+
+    assert_eq!(UBig::lcm_all(&[]), ubig!(1));
+    assert_eq!(UBig::lcm_all(&[ubig!(4), ubig!(0)]), ubig!(0));
+    assert_eq!(UBig::lcm_all(&[ubig!(4), ubig!(6), ubig!(10)]), ubig!(60));
+
+    assert_eq!(IBig::gcd_all(&[ibig!(-12), ibig!(18)]), ibig!(6));
+    assert_eq!(IBig::gcd_all(&[]), ibig!(0));
+    assert_eq!(IBig::lcm_all(&[ibig!(-4), ibig!(-6)]), ibig!(12));
+    assert_eq!(IBig::lcm_all(&[]), ibig!(1));
 }
\ No newline at end of file
@@ -1,5 +1,9 @@
 
-use ibig::{ibig, modular::ModuloRing, ubig};
+use ibig::{
+    ibig,
+    modular::{Modulo, ModuloRing},
+    ubig, UBig,
+};
 
 #[test]
 fn test_modulus() {
@@ -233,6 +237,27 @@ fn test_mul() {
     }
 }
 
+#[test]
+fn test_mul_huge_modulus() {
+    // Large enough to use a precomputed reciprocal of the modulus.
+    let bits = 400000;
+    if 2 * bits > UBig::MAX_BIT_LEN {
+        return;
+    }
+    let modulus = ubig!(3).pow(bits / 2) + ubig!(5).pow(bits / 3);
+    let ring = ModuloRing::new(&modulus);
+    let a = ubig!(7).pow(bits / 3) % &modulus;
+    let b = &modulus - ubig!(11).pow(bits / 5);
+    let product = &a * &b;
+    let expected = ring.from(&product % &modulus);
+    assert_eq!(ring.from(&a) * ring.from(&b), expected);
+    assert_eq!(ring.from(product), expected);
+    assert_eq!(
+        ring.from(&a).pow(&ubig!(3)),
+        ring.from(&a * &a % &modulus * &a)
+    );
+}
+
 #[test]
 fn test_inverse() {
     let ring = ModuloRing::new(&ubig!(1));
@@ -417,4 +442,31 @@ fn test_format() {
         format!("{:X}", x),
         "C9F2C9CD04674EDEA3FFFFFFF (mod C9F2C9CD04674EDEA40000000)"
     );
+}
+
+#[test]
+fn test_sum_product() {
+    let ring = ModuloRing::new(&ubig!(100));
+    let v = [ring.from(30), ring.from(40), ring.from(50)];
+    assert_eq!(ring.sum(&v), ring.from(20));
+    assert_eq!(ring.sum(v.iter().cloned()), ring.from(20));
+    assert_eq!(ring.sum(Vec::<Modulo>::new()), ring.from(0));
+    assert_eq!(ring.product(&v), ring.from(0));
+    assert_eq!(ring.product(&v[..2]), ring.from(0));
+    assert_eq!(ring.product(&[ring.from(7), ring.from(9)]), ring.from(63));
+    assert_eq!(ring.product(Vec::<Modulo>::new()), ring.from(1));
+
+    let ring = ModuloRing::new(&(ubig!(10).pow(50) + ubig!(151)));
+    let v: Vec<_> = (1..100u32).map(|i| ring.from(i)).collect();
+    let factorial: UBig = (1..100u32).map(UBig::from).product();
+    assert_eq!(ring.product(&v), ring.from(&factorial));
+    assert_eq!(ring.sum(&v), ring.from(4950));
+}
+
+#[test]
+#[should_panic]
+fn test_sum_different_rings() {
+    let ring1 = ModuloRing::new(&ubig!(100));
+    let ring2 = ModuloRing::new(&ubig!(100));
+    let _ = ring1.sum(&[ring2.from(1)]);
 }
\ No newline at end of file
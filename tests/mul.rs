@@ -1,9 +1,10 @@
 
 use core::{
+    convert::TryFrom,
     fmt::Debug,
     ops::{Mul, MulAssign},
 };
-use ibig::{ibig, ubig};
+use ibig::{ibig, ops::UnsignedAbs, ubig, IBig, Scratch, UBig};
 
 fn test_mul<'a, T>(a: &'a T, b: &'a T, c: &'a T)
 where
@@ -147,4 +148,225 @@ fn test_mul_ibig_primitive() {
     x *= 2;
     x *= &-2;
     assert_eq!(x, ibig!(12));
+}
+
+/// Multiply by splitting `b` into 64-bit pieces.
+fn mul_by_pieces(a: &UBig, b: &UBig) -> UBig {
+    let mask = (ubig!(1) << 64) - 1u8;
+    let mut result = ubig!(0);
+    let mut shift = 0;
+    while shift < b.bit_len() {
+        let piece = (b >> shift) & &mask;
+        result += (a * u64::try_from(piece).unwrap()) << shift;
+        shift += 64;
+    }
+    result
+}
+
+#[test]
+fn test_mul_ubig_unbalanced() {
+    // Lengths of the smaller factor in 64-bit units, large enough to use Toom-Cook algorithms.
+    let smaller_lens = [200, 257, 400];
+    // Ratios of the larger factor length to the smaller, in tenths.
+    let ratios = [10, 11, 13, 15, 16, 20, 21, 37, 100];
+
+    for &smaller_len in &smaller_lens {
+        for &ratio in &ratios {
+            let larger_len = smaller_len * ratio / 10;
+            if (larger_len + smaller_len) * 64 > UBig::MAX_BIT_LEN {
+                continue;
+            }
+            let factors = [
+                (
+                    ubig!(3).pow(larger_len * 40),
+                    ubig!(7).pow(smaller_len * 22),
+                ),
+                (
+                    (ubig!(1) << (larger_len * 64)) - 1u8,
+                    (ubig!(1) << (smaller_len * 64)) - 1u8,
+                ),
+                (
+                    (ubig!(1) << (larger_len * 64)) - 1u8,
+                    ubig!(5).pow(smaller_len * 27),
+                ),
+            ];
+            for (a, b) in &factors {
+                let c = mul_by_pieces(a, b);
+                test_mul(a, b, &c);
+                test_mul(b, a, &c);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_product() {
+    let v = [ubig!(2), ubig!(3), ubig!(4)];
+    assert_eq!(v.iter().product::<UBig>(), ubig!(24));
+    assert_eq!(v.iter().cloned().product::<UBig>(), ubig!(24));
+    assert_eq!(Vec::<UBig>::new().into_iter().product::<UBig>(), ubig!(1));
+
+    let v = [ibig!(2), ibig!(-3), ibig!(-4), ibig!(-5)];
+    assert_eq!(v.iter().product::<IBig>(), ibig!(-120));
+    assert_eq!(v.iter().cloned().product::<IBig>(), ibig!(-120));
+    assert_eq!(Vec::<IBig>::new().iter().product::<IBig>(), ibig!(1));
+
+    // Many factors, so that the product tree has several levels.
+    for n in [0u32, 1, 2, 3, 7, 8, 9, 100, 1000].iter() {
+        let factors: Vec<UBig> = (1..=*n).map(UBig::from).collect();
+        let mut expected = ubig!(1);
+        for x in &factors {
+            expected *= x;
+        }
+        assert_eq!(factors.iter().product::<UBig>(), expected);
+        assert_eq!(factors.into_iter().product::<UBig>(), expected);
+    }
+}
+
+/// Small numbers and numbers of `lens` words, for in-place operation tests.
+fn in_place_values(lens: &[usize]) -> Vec<UBig> {
+    let mut values = vec![
+        ubig!(0),
+        ubig!(1),
+        ubig!(0xffffffff),
+        ubig!(0xffffffffffffffff),
+    ];
+    for &len in lens {
+        values.push(ubig!(3).pow(len * 40));
+        values.push((ubig!(1) << (len * 64)) - 1u8);
+    }
+    values
+}
+
+#[test]
+fn test_mul_into() {
+    let mut scratch = Scratch::new();
+    let mut out = UBig::default();
+    let values = in_place_values(&[2, 3, 10, 50, 100, 300]);
+    for a in &values {
+        for b in &values {
+            UBig::mul_into(&mut out, a, b, &mut scratch);
+            assert_eq!(out, a * b);
+        }
+    }
+
+    let mut out = IBig::default();
+    for a in &values {
+        for b in &values {
+            let (a, b) = (IBig::from(a.clone()), -IBig::from(b.clone()));
+            IBig::mul_into(&mut out, &a, &b, &mut scratch);
+            assert_eq!(out, &a * &b);
+            IBig::mul_into(&mut out, &b, &b, &mut scratch);
+            assert_eq!(out, &b * &b);
+        }
+    }
+}
+
+#[test]
+fn test_mul_into_reuses_allocation() {
+    let mut scratch = Scratch::new();
+    let a = ubig!(3).pow(10000);
+    let b = ubig!(5).pow(10000);
+    let mut out = &a * &b;
+    let ptr = out.as_limbs().as_ptr();
+    for _ in 0..3 {
+        UBig::mul_into(&mut out, &a, &b, &mut scratch);
+        assert_eq!(out.as_limbs().as_ptr(), ptr);
+        assert_eq!(out, &a * &b);
+    }
+}
+
+#[test]
+fn test_add_mul_ubig() {
+    let mut scratch = Scratch::new();
+    let values = in_place_values(&[2, 3, 5, 50, 100]);
+    for a in &values {
+        for b in &values {
+            for c in &values {
+                let mut x = c.clone();
+                x.add_mul(a, b);
+                assert_eq!(x, c + a * b);
+
+                x.sub_mul(a, b);
+                assert_eq!(x, *c);
+
+                x.add_mul_with_scratch(a, b, &mut scratch);
+                assert_eq!(x, c + a * b);
+
+                x.sub_mul_with_scratch(a, b, &mut scratch);
+                assert_eq!(x, *c);
+
+                let mut x = a.clone();
+                x.mul_add_assign(b, c.clone());
+                assert_eq!(x, a * b + c);
+            }
+            for &w in &[0, 1, 0xff, u64::MAX] {
+                let mut x = b.clone();
+                x.add_mul_word(a, w as ibig::Word);
+                assert_eq!(x, b + a * (w as ibig::Word));
+            }
+        }
+    }
+
+    let mut x = ubig!(1000);
+    x.mul_add_assign(&ubig!(3), ubig!(7));
+    assert_eq!(x, ubig!(3007));
+}
+
+#[test]
+fn test_mul_add_assign_reuses_allocation() {
+    let b = ubig!(3).pow(10000);
+    let ptr = b.as_limbs().as_ptr();
+    let mut x = ubig!(5);
+    x.mul_add_assign(&ubig!(7), b);
+    assert_eq!(x.as_limbs().as_ptr(), ptr);
+    assert_eq!(x, ubig!(3).pow(10000) + 35u8);
+
+    let b = ubig!(3).pow(10000);
+    let ptr = b.as_limbs().as_ptr();
+    let mut x = ibig!(-5);
+    x.mul_add_assign(&ibig!(7), -IBig::from(b));
+    assert_eq!(x, -IBig::from(ubig!(3).pow(10000) + 35u8));
+    assert_eq!(x.unsigned_abs().as_limbs().as_ptr(), ptr);
+}
+
+#[test]
+#[should_panic]
+fn test_sub_mul_negative() {
+    let mut x = ubig!(5) << 1000;
+    x.sub_mul(&(ubig!(1) << 500), &(ubig!(6) << 500));
+}
+
+#[test]
+fn test_add_mul_ibig() {
+    let values: Vec<IBig> = in_place_values(&[2, 3, 5, 50, 100])
+        .into_iter()
+        .flat_map(|x| vec![IBig::from(x.clone()), -IBig::from(x)])
+        .collect();
+    let mut scratch = Scratch::new();
+    for a in &values {
+        for b in &values {
+            for c in &values {
+                let mut x = c.clone();
+                x.add_mul(a, b);
+                assert_eq!(x, c + a * b);
+
+                x.sub_mul(a, b);
+                assert_eq!(x, *c);
+
+                x.add_mul_with_scratch(a, b, &mut scratch);
+                assert_eq!(x, c + a * b);
+
+                x.sub_mul_with_scratch(a, b, &mut scratch);
+                assert_eq!(x, *c);
+
+                let mut x = a.clone();
+                x.mul_add_assign(b, c.clone());
+                assert_eq!(x, a * b + c);
+            }
+            let mut x = b.clone();
+            x.add_mul_word(a, 7);
+            assert_eq!(x, b + a * 7u8);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+use ibig::{ibig, ubig};
+use num_integer::{ExtendedGcd, Integer, Roots};
+
+#[test]
+fn test_integer_ubig() {
+    assert_eq!(ubig!(37).div_floor(&ubig!(7)), ubig!(5));
+    assert_eq!(ubig!(37).mod_floor(&ubig!(7)), ubig!(2));
+    assert_eq!(ubig!(37).div_mod_floor(&ubig!(7)), (ubig!(5), ubig!(2)));
+    assert_eq!(ubig!(37).div_ceil(&ubig!(7)), ubig!(6));
+    assert_eq!(Integer::gcd(&ubig!(12), &ubig!(18)), ubig!(6));
+    assert_eq!(Integer::gcd(&ubig!(0), &ubig!(0)), ubig!(0));
+    assert_eq!(Integer::lcm(&ubig!(12), &ubig!(18)), ubig!(36));
+    assert_eq!(ubig!(12).gcd_lcm(&ubig!(18)), (ubig!(6), ubig!(36)));
+    assert_eq!(ubig!(0).gcd_lcm(&ubig!(18)), (ubig!(18), ubig!(0)));
+    assert_eq!(ubig!(0).gcd_lcm(&ubig!(0)), (ubig!(0), ubig!(0)));
+    assert!(ubig!(12).is_multiple_of(&ubig!(4)));
+    assert!(!ubig!(12).is_multiple_of(&ubig!(5)));
+    assert!(ubig!(0).is_multiple_of(&ubig!(0)));
+    assert!(!ubig!(1).is_multiple_of(&ubig!(0)));
+    assert!(ubig!(12).is_even());
+    assert!(ubig!(13).is_odd());
+    assert_eq!(
+        Integer::div_rem(&ubig!(37), &ubig!(7)),
+        (ubig!(5), ubig!(2))
+    );
+    assert_eq!(ubig!(37).next_multiple_of(&ubig!(7)), ubig!(42));
+    assert_eq!(ubig!(37).prev_multiple_of(&ubig!(7)), ubig!(35));
+}
+
+#[test]
+fn test_integer_ibig() {
+    let test_cases = [
+        (ibig!(37), ibig!(7), ibig!(5), ibig!(2)),
+        (ibig!(-37), ibig!(7), ibig!(-6), ibig!(5)),
+        (ibig!(37), ibig!(-7), ibig!(-6), ibig!(-5)),
+        (ibig!(-37), ibig!(-7), ibig!(5), ibig!(-2)),
+        (ibig!(-35), ibig!(7), ibig!(-5), ibig!(0)),
+    ];
+    for (a, b, q, r) in &test_cases {
+        assert_eq!(a.div_floor(b), *q);
+        assert_eq!(a.mod_floor(b), *r);
+        assert_eq!(a.div_mod_floor(b), (q.clone(), r.clone()));
+    }
+
+    assert_eq!(Integer::gcd(&ibig!(-12), &ibig!(18)), ibig!(6));
+    assert_eq!(Integer::gcd(&ibig!(0), &ibig!(0)), ibig!(0));
+    assert_eq!(Integer::lcm(&ibig!(-12), &ibig!(18)), ibig!(36));
+    assert_eq!(ibig!(-12).gcd_lcm(&ibig!(-18)), (ibig!(6), ibig!(36)));
+    let ExtendedGcd { gcd, x, y, .. } = Integer::extended_gcd(&ibig!(-12), &ibig!(18));
+    assert_eq!(gcd, ibig!(6));
+    assert_eq!(x * ibig!(-12) + y * ibig!(18), ibig!(6));
+    let ExtendedGcd { gcd, x, y, .. } = Integer::extended_gcd(&ibig!(0), &ibig!(0));
+    assert_eq!(gcd, ibig!(0));
+    assert_eq!(x * ibig!(0) + y * ibig!(0), ibig!(0));
+    assert!(ibig!(-12).is_multiple_of(&ibig!(4)));
+    assert!(ibig!(-12).is_even());
+    assert!(ibig!(-13).is_odd());
+    assert_eq!(
+        Integer::div_rem(&ibig!(-37), &ibig!(7)),
+        (ibig!(-5), ibig!(-2))
+    );
+}
+
+#[test]
+fn test_roots() {
+    assert_eq!(Roots::sqrt(&ubig!(99)), ubig!(9));
+    assert_eq!(Roots::cbrt(&ubig!(1000)), ubig!(10));
+    assert_eq!(Roots::nth_root(&ubig!(1025), 10), ubig!(2));
+    assert_eq!(Roots::sqrt(&ibig!(99)), ibig!(9));
+    assert_eq!(Roots::cbrt(&ibig!(-999)), ibig!(-9));
+    assert_eq!(Roots::nth_root(&ibig!(-1025), 5), ibig!(-4));
+}
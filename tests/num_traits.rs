@@ -0,0 +1,131 @@
+use ibig::{ibig, modular::ModuloRing, ubig, IBig, UBig};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Euclid,
+    FromPrimitive, Inv, MulAdd, MulAddAssign, ToPrimitive,
+};
+
+#[test]
+fn test_to_primitive() {
+    assert_eq!(ubig!(100).to_u8(), Some(100));
+    assert_eq!(ubig!(1000).to_u8(), None);
+    assert_eq!(ubig!(1000).to_i16(), Some(1000));
+    assert_eq!((ubig!(1) << 64).to_u64(), None);
+    assert_eq!((ubig!(1) << 64).to_u128(), Some(1 << 64));
+    assert_eq!((ubig!(1) << 128).to_u128(), None);
+    assert_eq!(
+        ToPrimitive::to_f64(&(ubig!(1) << 100)),
+        Some(2.0f64.powi(100))
+    );
+    assert_eq!(ibig!(-5).to_i8(), Some(-5));
+    assert_eq!(ibig!(-5).to_u32(), None);
+    assert_eq!((ibig!(-1) << 127).to_i128(), Some(i128::MIN));
+    assert_eq!((ibig!(-1) << 128).to_i128(), None);
+    assert_eq!(ToPrimitive::to_f32(&ibig!(-3)), Some(-3.0));
+}
+
+#[test]
+fn test_from_primitive() {
+    assert_eq!(UBig::from_i32(5), Some(ubig!(5)));
+    assert_eq!(UBig::from_i32(-5), None);
+    assert_eq!(UBig::from_u128(u128::MAX), Some(UBig::from(u128::MAX)));
+    assert_eq!(IBig::from_i64(-5), Some(ibig!(-5)));
+    assert_eq!(IBig::from_i128(i128::MIN), Some(IBig::from(i128::MIN)));
+
+    assert_eq!(UBig::from_f64(3.99), Some(ubig!(3)));
+    assert_eq!(UBig::from_f64(0.5), Some(ubig!(0)));
+    assert_eq!(UBig::from_f64(-0.5), Some(ubig!(0)));
+    assert_eq!(UBig::from_f64(-1.5), None);
+    assert_eq!(UBig::from_f64(2.0f64.powi(100)), Some(ubig!(1) << 100));
+    assert_eq!(UBig::from_f64(f64::INFINITY), None);
+    assert_eq!(UBig::from_f64(f64::NAN), None);
+    assert_eq!(IBig::from_f64(-3.99), Some(ibig!(-3)));
+    assert_eq!(
+        IBig::from_f64(-1e30),
+        Some(ibig!(-1000000000000000019884624838656))
+    );
+    assert_eq!(IBig::from_f32(-2.5), Some(ibig!(-2)));
+    assert_eq!(
+        IBig::from_f64(f64::MAX),
+        Some(IBig::from_u64((1 << 53) - 1).unwrap() << 971)
+    );
+}
+
+#[test]
+fn test_num_cast() {
+    assert_eq!(<UBig as num_traits::NumCast>::from(5u8), Some(ubig!(5)));
+    assert_eq!(<UBig as num_traits::NumCast>::from(-5i8), None);
+    assert_eq!(
+        <UBig as num_traits::NumCast>::from(1e20f64),
+        Some(ubig!(100000000000000000000))
+    );
+    assert_eq!(
+        <UBig as num_traits::NumCast>::from(1e40f64),
+        Some(UBig::from_f64(1e40).unwrap())
+    );
+    assert_eq!(
+        <IBig as num_traits::NumCast>::from(u128::MAX),
+        Some(IBig::from(u128::MAX))
+    );
+    assert_eq!(<IBig as num_traits::NumCast>::from(-7i64), Some(ibig!(-7)));
+    assert_eq!(
+        <IBig as num_traits::NumCast>::from(-1e40f64),
+        Some(IBig::from_f64(-1e40).unwrap())
+    );
+    assert_eq!(<IBig as num_traits::NumCast>::from(f32::NAN), None);
+}
+
+#[test]
+fn test_checked() {
+    assert_eq!(ubig!(5).checked_add(&ubig!(7)), Some(ubig!(12)));
+    assert_eq!(ubig!(5).checked_sub(&ubig!(7)), None);
+    assert_eq!(ubig!(7).checked_sub(&ubig!(5)), Some(ubig!(2)));
+    assert_eq!(ubig!(5).checked_mul(&ubig!(7)), Some(ubig!(35)));
+    assert_eq!(ubig!(35).checked_div(&ubig!(7)), Some(ubig!(5)));
+    assert_eq!(ubig!(35).checked_div(&ubig!(0)), None);
+    assert_eq!(ubig!(37).checked_rem(&ubig!(7)), Some(ubig!(2)));
+    assert_eq!(ubig!(37).checked_rem(&ubig!(0)), None);
+    assert_eq!(ubig!(0).checked_neg(), Some(ubig!(0)));
+    assert_eq!(ubig!(1).checked_neg(), None);
+
+    assert_eq!(ibig!(5).checked_add(&ibig!(-7)), Some(ibig!(-2)));
+    assert_eq!(ibig!(5).checked_sub(&ibig!(7)), Some(ibig!(-2)));
+    assert_eq!(ibig!(5).checked_mul(&ibig!(-7)), Some(ibig!(-35)));
+    assert_eq!(ibig!(-37).checked_div(&ibig!(7)), Some(ibig!(-5)));
+    assert_eq!(ibig!(-37).checked_div(&ibig!(0)), None);
+    assert_eq!(ibig!(-37).checked_rem(&ibig!(7)), Some(ibig!(-2)));
+    assert_eq!(ibig!(-37).checked_rem(&ibig!(0)), None);
+    assert_eq!(ibig!(5).checked_neg(), Some(ibig!(-5)));
+}
+
+#[test]
+fn test_euclid() {
+    assert_eq!(Euclid::div_euclid(&ubig!(37), &ubig!(7)), ubig!(5));
+    assert_eq!(Euclid::rem_euclid(&ubig!(37), &ubig!(7)), ubig!(2));
+    assert_eq!(Euclid::div_euclid(&ibig!(-37), &ibig!(7)), ibig!(-6));
+    assert_eq!(Euclid::rem_euclid(&ibig!(-37), &ibig!(7)), ibig!(5));
+    assert_eq!(Euclid::div_euclid(&ibig!(-37), &ibig!(-7)), ibig!(6));
+    assert_eq!(Euclid::rem_euclid(&ibig!(-37), &ibig!(-7)), ibig!(5));
+    assert_eq!(ubig!(37).checked_div_euclid(&ubig!(0)), None);
+    assert_eq!(ubig!(37).checked_rem_euclid(&ubig!(7)), Some(ubig!(2)));
+    assert_eq!(ibig!(-37).checked_div_euclid(&ibig!(7)), Some(ibig!(-6)));
+    assert_eq!(ibig!(-37).checked_rem_euclid(&ibig!(0)), None);
+}
+
+#[test]
+fn test_mul_add() {
+    assert_eq!(ubig!(3).mul_add(ubig!(4), ubig!(5)), ubig!(17));
+    assert_eq!(ibig!(3).mul_add(ibig!(-4), ibig!(5)), ibig!(-7));
+    let mut x = ubig!(3);
+    MulAddAssign::mul_add_assign(&mut x, ubig!(4), ubig!(5));
+    assert_eq!(x, ubig!(17));
+    let mut x = ibig!(-3);
+    MulAddAssign::mul_add_assign(&mut x, ibig!(4), ibig!(5));
+    assert_eq!(x, ibig!(-7));
+}
+
+#[test]
+fn test_inv() {
+    let ring = ModuloRing::new(&ubig!(10));
+    assert_eq!(ring.from(7).inv(), Some(ring.from(3)));
+    assert_eq!((&ring.from(2)).inv(), None);
+}
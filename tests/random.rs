@@ -1,5 +1,12 @@
-use ibig::{ibig, ops::DivRem, ubig, UBig};
+use ibig::{
+    ibig,
+    modular::ModuloRing,
+    ops::{DivRem, UnsignedAbs},
+    rand::{RandomBitRuns, RandomBits, RandomIBig},
+    ubig, UBig,
+};
 use rand::{distributions::uniform::Uniform, prelude::*};
+use std::convert::TryFrom;
 
 #[test]
 fn test_uniform_ubig() {
@@ -76,3 +83,162 @@ fn test_random_arithmetic() {
         }
     }
 }
+
+#[test]
+fn test_random_bits() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    assert_eq!(rng.sample(RandomBits::new(0)), ubig!(0));
+
+    let distr = RandomBits::new(3);
+    let x = (&mut rng).sample_iter(&distr).take(1000).min().unwrap();
+    assert_eq!(x, ubig!(0));
+    let x = (&mut rng).sample_iter(&distr).take(1000).max().unwrap();
+    assert_eq!(x, ubig!(7));
+
+    let distr = RandomBits::new(3).exact_top_bit(true).odd(true);
+    let x = (&mut rng).sample_iter(&distr).take(1000).min().unwrap();
+    assert_eq!(x, ubig!(5));
+    let x = (&mut rng).sample_iter(&distr).take(1000).max().unwrap();
+    assert_eq!(x, ubig!(7));
+
+    assert_eq!(
+        rng.sample(RandomBits::new(1).exact_top_bit(true).odd(true)),
+        ubig!(1)
+    );
+
+    for bits in 1..300 {
+        let x = rng.sample(RandomBits::new(bits));
+        assert!(x.bit_len() <= bits);
+        let x = rng.sample(RandomBits::new(bits).exact_top_bit(true));
+        assert_eq!(x.bit_len(), bits);
+        let x = rng.sample(RandomBits::new(bits).odd(true));
+        assert!(x.bit_len() <= bits && x.bit(0));
+    }
+
+    // Each bit is set about half the time.
+    let distr = RandomBits::new(200);
+    let mut counts = [0; 200];
+    for x in (&mut rng).sample_iter(&distr).take(1000) {
+        for (i, count) in counts.iter_mut().enumerate() {
+            *count += x.bit(i) as u32;
+        }
+    }
+    assert!(counts.iter().all(|&count| count > 400 && count < 600));
+}
+
+#[test]
+#[should_panic]
+fn test_random_bits_odd_zero() {
+    let _ = RandomBits::new(0).odd(true);
+}
+
+#[test]
+fn test_random_ibig() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let distr = RandomIBig::new(0);
+    assert!((&mut rng)
+        .sample_iter(&distr)
+        .take(10)
+        .all(|x| x == ibig!(0)));
+
+    let distr = RandomIBig::new(100);
+    let samples: Vec<_> = (&mut rng).sample_iter(&distr).take(10000).collect();
+    assert!(samples.iter().all(|x| x.unsigned_abs().bit_len() <= 100));
+    assert!(samples.contains(&ibig!(0)));
+    assert!(samples.contains(&ibig!(1)));
+    assert!(samples.contains(&ibig!(-1)));
+    let negative = samples.iter().filter(|x| **x < ibig!(0)).count();
+    assert!(negative > 4000 && negative < 6000);
+    let small = samples
+        .iter()
+        .filter(|x| x.unsigned_abs().bit_len() <= 10)
+        .count();
+    assert!(small > 500 && small < 1500);
+}
+
+#[test]
+fn test_random_bit_runs() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    assert_eq!(rng.sample(RandomBitRuns::new(0)), ubig!(0));
+    assert_eq!(rng.sample(RandomBitRuns::new(1)), ubig!(1));
+
+    for bits in 1..300 {
+        let x = rng.sample(RandomBitRuns::new(bits));
+        assert_eq!(x.bit_len(), bits);
+    }
+
+    // Long runs of both 0s and 1s appear.
+    let distr = RandomBitRuns::new(1000);
+    let mut longest = [0; 2];
+    for x in (&mut rng).sample_iter(&distr).take(100) {
+        let mut run = 0;
+        for i in 0..1000 {
+            if i > 0 && x.bit(i) != x.bit(i - 1) {
+                run = 0;
+            }
+            run += 1;
+            let longest = &mut longest[x.bit(i) as usize];
+            *longest = (*longest).max(run);
+        }
+    }
+    assert!(longest[0] >= 200 && longest[1] >= 200);
+
+    // Carries propagate through long runs of 1s.
+    for _ in 0..1000 {
+        let a = rng.sample(RandomBitRuns::new(500));
+        let b = rng.sample(RandomBitRuns::new(300));
+        assert_eq!(&a + &b - &b, a);
+        assert_eq!((&a * &b) / &b, a);
+    }
+}
+
+#[test]
+fn test_random_modulo() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let ring = ModuloRing::new(&ubig!(1));
+    assert_eq!(ring.random(&mut rng), ring.from(0));
+    assert_eq!(ring.random_unit(&mut rng), ring.from(0));
+
+    let ring = ModuloRing::new(&ubig!(10));
+    let residues: Vec<_> = (0..1000).map(|_| ring.random(&mut rng).residue()).collect();
+    assert_eq!(residues.iter().min(), Some(&ubig!(0)));
+    assert_eq!(residues.iter().max(), Some(&ubig!(9)));
+    let residues: Vec<_> = (&mut rng)
+        .sample_iter(&ring)
+        .take(1000)
+        .map(|x| x.residue())
+        .collect();
+    assert_eq!(residues.iter().min(), Some(&ubig!(0)));
+    assert_eq!(residues.iter().max(), Some(&ubig!(9)));
+
+    let mut units = [0; 10];
+    for _ in 0..1000 {
+        let x = ring.random_unit(&mut rng);
+        units[usize::try_from(x.residue()).unwrap()] += 1;
+    }
+    for (i, &count) in units.iter().enumerate() {
+        if i % 2 == 1 && i != 5 {
+            assert!(count > 150 && count < 350);
+        } else {
+            assert_eq!(count, 0);
+        }
+    }
+
+    // 2 * 3 * 5 * ... * 97 * 2^200
+    let mut n = ubig!(1) << 200;
+    for p in (2u32..100).filter(|p| (2..*p).all(|d| p % d != 0)) {
+        n *= p;
+    }
+    let ring = ModuloRing::new(&n);
+    for _ in 0..100 {
+        let x = ring.random(&mut rng);
+        assert!(x.residue() < n);
+        let x = ring.random_unit(&mut rng);
+        assert_eq!(x.residue().gcd(&n), ubig!(1));
+        assert_eq!(&x * x.inverse().unwrap(), ring.from(1));
+    }
+}
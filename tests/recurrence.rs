@@ -0,0 +1,108 @@
+use ibig::{ibig, modular::ModuloRing, ubig, IBig, UBig};
+
+#[test]
+fn test_fibonacci() {
+    let mut f = (ubig!(0), ubig!(1));
+    for n in 0..300 {
+        assert_eq!(UBig::fibonacci(n), f.0);
+        f = (f.1.clone(), f.0 + f.1);
+    }
+    assert_eq!(
+        UBig::fibonacci(200),
+        ubig!(_280571172992510140037611932413038677189525)
+    );
+}
+
+#[test]
+fn test_lucas() {
+    let mut l = (ubig!(2), ubig!(1));
+    for n in 0..300 {
+        assert_eq!(UBig::lucas(n), l.0);
+        l = (l.1.clone(), l.0 + l.1);
+    }
+    // L(n) = F(n-1) + F(n+1)
+    let n = 10000;
+    assert_eq!(
+        UBig::lucas(n),
+        UBig::fibonacci(n - 1) + UBig::fibonacci(n + 1)
+    );
+}
+
+#[test]
+fn test_lucas_sequence() {
+    for (p, q) in [(1, -1), (3, 2), (-2, 5), (0, 0), (4, 4), (-7, -3)].iter() {
+        let (p, q) = (IBig::from(*p), IBig::from(*q));
+        let mut u = (ibig!(0), ibig!(1));
+        let mut v = (ibig!(2), p.clone());
+        for n in 0..100 {
+            assert_eq!(IBig::lucas_sequence(n, &p, &q), (u.0.clone(), v.0.clone()));
+            u = (u.1.clone(), &p * &u.1 - &q * u.0);
+            v = (v.1.clone(), &p * &v.1 - &q * v.0);
+        }
+    }
+}
+
+#[test]
+fn test_linear_recurrence() {
+    for (a, b, x0, x1) in [(1, 1, 0, 1), (2, 1, 0, 1), (-3, 7, 5, -2), (0, 1, 1, 2)].iter() {
+        let (a, b, x0, x1) = (
+            IBig::from(*a),
+            IBig::from(*b),
+            IBig::from(*x0),
+            IBig::from(*x1),
+        );
+        let mut x = (x0.clone(), x1.clone());
+        for n in 0..100 {
+            assert_eq!(IBig::linear_recurrence(n, &a, &b, &x0, &x1), x.0);
+            x = (x.1.clone(), &a * &x.1 + &b * x.0);
+        }
+    }
+}
+
+#[test]
+fn test_modular() {
+    for modulus in [
+        ubig!(1),
+        ubig!(2),
+        ubig!(1000),
+        ubig!(10).pow(40) + ubig!(7),
+    ]
+    .iter()
+    {
+        let ring = ModuloRing::new(modulus);
+        for &n in &[0, 1, 2, 10, 99, 100, 1000] {
+            assert_eq!(ring.fibonacci(n), ring.from(UBig::fibonacci(n)));
+            assert_eq!(ring.lucas(n), ring.from(UBig::lucas(n)));
+            let (p, q) = (ibig!(-2), ibig!(5));
+            let (u, v) = IBig::lucas_sequence(n, &p, &q);
+            assert_eq!(
+                ring.lucas_sequence(n, &ring.from(&p), &ring.from(&q)),
+                (ring.from(u), ring.from(v))
+            );
+            let (a, b, x0, x1) = (ibig!(-3), ibig!(7), ibig!(5), ibig!(-2));
+            let x = IBig::linear_recurrence(n, &a, &b, &x0, &x1);
+            assert_eq!(
+                ring.linear_recurrence(
+                    n,
+                    &ring.from(&a),
+                    &ring.from(&b),
+                    &ring.from(&x0),
+                    &ring.from(&x1)
+                ),
+                ring.from(x)
+            );
+        }
+    }
+
+    // Fibonacci numbers modulo 10^k have period 15 * 10^(k-1).
+    let ring = ModuloRing::new(&ubig!(10).pow(9));
+    assert_eq!(ring.fibonacci(15 * 10usize.pow(8) + 17), ring.fibonacci(17));
+}
+
+#[test]
+#[should_panic]
+fn test_lucas_sequence_different_rings() {
+    let ring1 = ModuloRing::new(&ubig!(100));
+    let ring2 = ModuloRing::new(&ubig!(100));
+    let _ = ring1.lucas_sequence(10, &ring2.from(1), &ring2.from(1));
+}
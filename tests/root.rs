@@ -0,0 +1,77 @@
+use ibig::{ibig, ubig, UBig};
+
+fn check_nth_root(x: &UBig, n: usize) {
+    let r = x.nth_root(n);
+    assert!(r.pow(n) <= *x);
+    assert!((r + ubig!(1)).pow(n) > *x);
+}
+
+#[test]
+fn test_nth_root_ubig() {
+    let test_cases = [
+        (ubig!(0), 1, ubig!(0)),
+        (ubig!(0), 5, ubig!(0)),
+        (ubig!(1), 5, ubig!(1)),
+        (ubig!(100), 1, ubig!(100)),
+        (ubig!(3), 2, ubig!(1)),
+        (ubig!(4), 2, ubig!(2)),
+        (ubig!(99), 2, ubig!(9)),
+        (ubig!(100), 2, ubig!(10)),
+        (ubig!(100), 100, ubig!(1)),
+        (ubig!(1023), 10, ubig!(1)),
+        (ubig!(1024), 10, ubig!(2)),
+        (ubig!(_1474913153392179474539944683), 13, ubig!(123)),
+        (ubig!(_1474913153392179474539944682), 13, ubig!(122)),
+    ];
+
+    for (a, b, c) in &test_cases {
+        assert_eq!(a.nth_root(*b), *c);
+    }
+
+    assert_eq!(ubig!(35).sqrt(), ubig!(5));
+    assert_eq!(ubig!(36).sqrt(), ubig!(6));
+
+    for x in 0u16..1000 {
+        for n in 1..12 {
+            check_nth_root(&UBig::from(x), n);
+        }
+    }
+}
+
+#[test]
+fn test_nth_root_ubig_large() {
+    for &n in &[2, 3, 5, 64, 1000] {
+        for &bits in &[100, 1000, 10000, 100000] {
+            if 2 * bits > UBig::MAX_BIT_LEN {
+                continue;
+            }
+            let x = ubig!(3).pow(bits * 100 / 158);
+            check_nth_root(&x, n);
+            check_nth_root(&(&x - ubig!(1)), n);
+            let r = x.nth_root(n) + ubig!(12345);
+            assert_eq!(r.pow(n).nth_root(n), r);
+            assert_eq!((r.pow(n) - ubig!(1)).nth_root(n), r - ubig!(1));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_nth_root_0() {
+    let _ = ubig!(10).nth_root(0);
+}
+
+#[test]
+fn test_nth_root_ibig() {
+    assert_eq!(ibig!(35).sqrt(), ibig!(5));
+    assert_eq!(ibig!(124).nth_root(3), ibig!(4));
+    assert_eq!(ibig!(-124).nth_root(3), ibig!(-4));
+    assert_eq!(ibig!(-125).nth_root(3), ibig!(-5));
+    assert_eq!(ibig!(0).nth_root(4), ibig!(0));
+}
+
+#[test]
+#[should_panic]
+fn test_sqrt_negative() {
+    let _ = ibig!(-4).sqrt();
+}
@@ -1,6 +1,9 @@
 
-use ibig::{ibig, ubig};
-use serde_test::{assert_de_tokens, assert_tokens, Token};
+use ibig::{ibig, ubig, IBig, UBig};
+use serde::{Deserialize, Serialize};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Readable, Token,
+};
 
 #[test]
 fn test_ubig_serde() {
@@ -90,4 +93,369 @@ fn test_ibig_serde() {
             Token::TupleEnd,
         ],
     );
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Decimal {
+    #[serde(with = "ibig::serde::decimal")]
+    a: UBig,
+    #[serde(with = "ibig::serde::decimal")]
+    b: IBig,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Hex {
+    #[serde(with = "ibig::serde::hex")]
+    a: UBig,
+    #[serde(with = "ibig::serde::hex")]
+    b: IBig,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct Bytes {
+    #[serde(with = "ibig::serde::bytes")]
+    a: UBig,
+    #[serde(with = "ibig::serde::bytes")]
+    b: IBig,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct Compact {
+    #[serde(with = "ibig::serde::compact")]
+    a: UBig,
+    #[serde(with = "ibig::serde::compact")]
+    b: IBig,
+}
+
+#[test]
+fn test_decimal_json() {
+    let x = Decimal {
+        a: ubig!(1) << 100,
+        b: -(ibig!(1) << 100),
+    };
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(
+        json,
+        r#"{"a":"1267650600228229401496703205376","b":"-1267650600228229401496703205376"}"#
+    );
+    assert_eq!(serde_json::from_str::<Decimal>(&json).unwrap(), x);
+
+    assert_eq!(
+        serde_json::from_str::<Decimal>(r#"{"a":"+0","b":"+17"}"#).unwrap(),
+        Decimal {
+            a: ubig!(0),
+            b: ibig!(17)
+        }
+    );
+    assert_eq!(
+        serde_json::from_str::<Decimal>(r#"{"a":"-0","b":"-0"}"#).unwrap(),
+        Decimal {
+            a: ubig!(0),
+            b: ibig!(0)
+        }
+    );
+    assert_eq!(
+        serde_json::from_str::<Decimal>(r#"{"a":18446744073709551615,"b":-9223372036854775808}"#)
+            .unwrap(),
+        Decimal {
+            a: UBig::from(u64::MAX),
+            b: IBig::from(i64::MIN)
+        }
+    );
+
+    for json in &[
+        r#"{"a":"-1","b":"0"}"#,
+        r#"{"a":-1,"b":0}"#,
+        r#"{"a":"","b":"0"}"#,
+        r#"{"a":"0","b":"-"}"#,
+        r#"{"a":"0","b":"+-1"}"#,
+        r#"{"a":"0","b":"-+1"}"#,
+        r#"{"a":"0x10","b":"0"}"#,
+        r#"{"a":"1a","b":"0"}"#,
+        r#"{"a":1.5,"b":0}"#,
+    ] {
+        assert!(serde_json::from_str::<Decimal>(json).is_err(), "{}", json);
+    }
+}
+
+#[test]
+fn test_hex_json() {
+    let x = Hex {
+        a: ubig!(0x123456789abcdef0123456789),
+        b: ibig!(-0xff),
+    };
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, r#"{"a":"0x123456789abcdef0123456789","b":"-0xff"}"#);
+    assert_eq!(serde_json::from_str::<Hex>(&json).unwrap(), x);
+
+    assert_eq!(
+        serde_json::to_string(&Hex {
+            a: ubig!(0),
+            b: ibig!(0)
+        })
+        .unwrap(),
+        r#"{"a":"0x0","b":"0x0"}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Hex>(r#"{"a":"FF","b":"+0XaB"}"#).unwrap(),
+        Hex {
+            a: ubig!(0xff),
+            b: ibig!(0xab)
+        }
+    );
+    assert_eq!(
+        serde_json::from_str::<Hex>(r#"{"a":255,"b":-255}"#).unwrap(),
+        Hex {
+            a: ubig!(0xff),
+            b: ibig!(-0xff)
+        }
+    );
+
+    for json in &[
+        r#"{"a":"-0x1","b":"0"}"#,
+        r#"{"a":"0x","b":"0"}"#,
+        r#"{"a":"0","b":"0x-1"}"#,
+        r#"{"a":"0","b":"0x+1"}"#,
+        r#"{"a":"0xg","b":"0"}"#,
+    ] {
+        assert!(serde_json::from_str::<Hex>(json).is_err(), "{}", json);
+    }
+}
+
+#[test]
+fn test_bytes_serde() {
+    let x = Bytes {
+        a: ubig!(0x1234),
+        b: ibig!(-0x80),
+    };
+    let tokens = [
+        Token::Struct {
+            name: "Bytes",
+            len: 2,
+        },
+        Token::Str("a"),
+        Token::Bytes(&[0x12, 0x34]),
+        Token::Str("b"),
+        Token::Bytes(&[0x80]),
+        Token::StructEnd,
+    ];
+    assert_tokens(&x.clone().readable(), &tokens);
+    assert_tokens(&x.compact(), &tokens);
+
+    assert_de_tokens(
+        &Bytes {
+            a: ubig!(0),
+            b: ibig!(0x80),
+        },
+        &[
+            Token::Struct {
+                name: "Bytes",
+                len: 2,
+            },
+            Token::Str("a"),
+            Token::Bytes(&[]),
+            Token::Str("b"),
+            Token::Seq { len: Some(2) },
+            Token::U8(0),
+            Token::U8(0x80),
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+
+    let x = Bytes {
+        a: ubig!(1) << 200,
+        b: -(ibig!(1) << 200),
+    };
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(serde_json::from_str::<Bytes>(&json).unwrap(), x);
+}
+
+#[test]
+fn test_compact_serde() {
+    let x = Compact {
+        a: ubig!(1000),
+        b: ibig!(-1000),
+    };
+    assert_tokens(
+        &x.clone().readable(),
+        &[
+            Token::Struct {
+                name: "Compact",
+                len: 2,
+            },
+            Token::Str("a"),
+            Token::Str("1000"),
+            Token::Str("b"),
+            Token::Str("-1000"),
+            Token::StructEnd,
+        ],
+    );
+    assert_tokens(
+        &x.clone().compact(),
+        &[
+            Token::Struct {
+                name: "Compact",
+                len: 2,
+            },
+            Token::Str("a"),
+            Token::Bytes(&[0x03, 0xe8]),
+            Token::Str("b"),
+            Token::Bytes(&[0xfc, 0x18]),
+            Token::StructEnd,
+        ],
+    );
+    assert_de_tokens(
+        &x.readable(),
+        &[
+            Token::Struct {
+                name: "Compact",
+                len: 2,
+            },
+            Token::Str("a"),
+            Token::U16(1000),
+            Token::Str("b"),
+            Token::I16(-1000),
+            Token::StructEnd,
+        ],
+    );
+    assert_de_tokens_error::<Readable<Compact>>(
+        &[
+            Token::Struct {
+                name: "Compact",
+                len: 2,
+            },
+            Token::Str("a"),
+            Token::I8(-1),
+        ],
+        "invalid value: integer `-1`, expected an integer or a string of base 10 digits",
+    );
+}
+
+#[test]
+fn test_ubig_serde_malformed() {
+    // Size hints are not trusted.
+    assert_de_tokens(
+        &ubig!(5),
+        &[Token::Seq { len: Some(0) }, Token::U64(5), Token::SeqEnd],
+    );
+    assert_de_tokens(
+        &ubig!(0x50000000000000003),
+        &[
+            Token::Seq { len: Some(1) },
+            Token::U64(3),
+            Token::U64(5),
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens(
+        &ubig!(5),
+        &[Token::Seq { len: Some(3) }, Token::U64(5), Token::SeqEnd],
+    );
+    assert_de_tokens(
+        &ubig!(5),
+        &[
+            Token::Seq {
+                len: Some(usize::MAX),
+            },
+            Token::U64(5),
+            Token::SeqEnd,
+        ],
+    );
+    // Leading zeros are accepted by default.
+    assert_de_tokens(
+        &ubig!(5),
+        &[
+            Token::Seq { len: Some(3) },
+            Token::U64(5),
+            Token::U64(0),
+            Token::U64(0),
+            Token::SeqEnd,
+        ],
+    );
+
+    assert_de_tokens_error::<UBig>(
+        &[Token::Seq { len: Some(1) }, Token::I64(-1)],
+        "invalid value: integer `-1`, expected u64",
+    );
+    assert_de_tokens_error::<UBig>(
+        &[Token::Seq { len: Some(1) }, Token::Str("1")],
+        "invalid type: string \"1\", expected u64",
+    );
+    assert_de_tokens_error::<UBig>(
+        &[Token::U64(1)],
+        "invalid type: integer `1`, expected a sequence of 64-bit words",
+    );
+
+    for json in &[
+        "[1,-1]",
+        "[1.5]",
+        "[18446744073709551616]",
+        "[1,",
+        "{}",
+        "\"1\"",
+    ] {
+        assert!(serde_json::from_str::<UBig>(json).is_err(), "{}", json);
+    }
+    for json in &[
+        "[1,2,3]",
+        "[-1]",
+        "[null]",
+        r#"["Zero",[]]"#,
+        r#"["Negative",[1],2]"#,
+    ] {
+        assert!(serde_json::from_str::<IBig>(json).is_err(), "{}", json);
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Canonical {
+    #[serde(with = "ibig::serde::canonical")]
+    a: UBig,
+    #[serde(with = "ibig::serde::canonical")]
+    b: IBig,
+}
+
+#[test]
+fn test_canonical_serde() {
+    let x = Canonical {
+        a: ubig!(0x50000000000000003),
+        b: ibig!(-5),
+    };
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, r#"{"a":[3,5],"b":["Negative",[5]]}"#);
+    assert_eq!(serde_json::from_str::<Canonical>(&json).unwrap(), x);
+    assert_eq!(
+        serde_json::from_str::<Canonical>(r#"{"a":[],"b":["Positive",[]]}"#).unwrap(),
+        Canonical {
+            a: ubig!(0),
+            b: ibig!(0)
+        }
+    );
+
+    for json in &[
+        r#"{"a":[0],"b":["Positive",[]]}"#,
+        r#"{"a":[3,0],"b":["Positive",[]]}"#,
+        r#"{"a":[],"b":["Positive",[0]]}"#,
+        r#"{"a":[],"b":["Negative",[]]}"#,
+        r#"{"a":[],"b":["Negative",[5,0]]}"#,
+    ] {
+        assert!(serde_json::from_str::<Canonical>(json).is_err(), "{}", json);
+    }
+
+    assert_de_tokens_error::<UBigCanonical>(
+        &[
+            Token::NewtypeStruct {
+                name: "UBigCanonical",
+            },
+            Token::Seq { len: Some(2) },
+            Token::U64(3),
+            Token::U64(0),
+            Token::SeqEnd,
+        ],
+        "invalid value: integer `0`, expected a sequence of 64-bit words with no leading zeros",
+    );
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct UBigCanonical(#[serde(with = "ibig::serde::canonical")] UBig);
\ No newline at end of file
@@ -1,5 +1,5 @@
 
-use ibig::{ibig, ubig};
+use ibig::{ibig, ubig, UBig};
 
 #[test]
 #[allow(clippy::identity_op, clippy::op_ref)]
@@ -217,4 +217,90 @@ fn test_ibig_shr() {
         x >>= *b;
         assert_eq!(x, *c);
     }
+}
+
+fn test_values() -> Vec<UBig> {
+    vec![
+        ubig!(0),
+        ubig!(1),
+        ubig!(0b1001_0110),
+        ubig!(0xffffffffffffffff),
+        ubig!(_0x123456789abcdef0123456789abcdef0fedcba9876543210),
+        (ubig!(1) << 300) - ubig!(1),
+        ubig!(1) << 299 | ubig!(0x1234),
+    ]
+}
+
+fn naive_rotate_left(x: &UBig, n: usize, width: usize) -> UBig {
+    let mut result = ubig!(0);
+    for i in 0..width {
+        if x.bit(i) {
+            result.set_bit((i + n) % width);
+        }
+    }
+    result
+}
+
+#[test]
+fn test_truncate_split() {
+    for x in &test_values() {
+        for &k in &[0, 1, 7, 8, 63, 64, 65, 128, 200, 299, 300, 301, 1000] {
+            let mask = (ubig!(1) << k) - ubig!(1);
+            assert_eq!(x.truncate_bits(k), x & &mask);
+            let (low, high) = x.split_at_bit(k);
+            assert_eq!(low, x & &mask);
+            assert_eq!(high, x >> k);
+            assert_eq!(x.clone().into_split_at_bit(k), (low, high));
+        }
+    }
+}
+
+#[test]
+fn test_rotate() {
+    for x in &test_values() {
+        for &width in &[300, 301, 320, 512] {
+            for &n in &[0, 1, 3, 63, 64, 65, 150, 299, 300, 1000] {
+                let expected = naive_rotate_left(x, n, width);
+                assert_eq!(x.rotate_left(n, width), expected);
+                assert_eq!(x.rotate_right(width - n % width, width), expected);
+                assert_eq!(expected.rotate_right(n, width), *x);
+            }
+        }
+    }
+    assert_eq!(ubig!(0).rotate_left(5, 0), ubig!(0));
+    assert_eq!(ubig!(1).rotate_right(5, 1), ubig!(1));
+    assert_eq!(ubig!(0b1).rotate_right(1, 64), ubig!(1) << 63);
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_too_wide() {
+    let _ = ubig!(0x100).rotate_left(1, 8);
+}
+
+#[test]
+fn test_reverse_bits() {
+    for x in &test_values() {
+        for &width in &[300, 301, 320, 511, 512] {
+            let mut expected = ubig!(0);
+            for i in 0..width {
+                if x.bit(i) {
+                    expected.set_bit(width - 1 - i);
+                }
+            }
+            let reversed = x.reverse_bits(width);
+            assert_eq!(reversed, expected);
+            assert_eq!(reversed.reverse_bits(width), *x);
+        }
+    }
+    assert_eq!(ubig!(0).reverse_bits(0), ubig!(0));
+    assert_eq!(ubig!(1).reverse_bits(1), ubig!(1));
+    assert_eq!(ubig!(0b110).reverse_bits(3), ubig!(0b011));
+    assert_eq!(ubig!(1).reverse_bits(64), ubig!(1) << 63);
+}
+
+#[test]
+#[should_panic]
+fn test_reverse_bits_too_wide() {
+    let _ = ubig!(0b100).reverse_bits(2);
 }
\ No newline at end of file